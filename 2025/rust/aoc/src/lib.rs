@@ -0,0 +1,30 @@
+use aoc_core::Registry;
+use aoc_runner::Workspace;
+use std::path::Path;
+
+/// Every day in the workspace. A new day needs to
+/// be added here and to `aoc/Cargo.toml`.
+pub const REGISTRY: Registry = Registry::new(&[
+    day_01::DAY,
+    day_02::DAY,
+    day_03::DAY,
+    day_04::DAY,
+    day_05::DAY,
+    day_06::DAY,
+    day_07::DAY,
+    day_08::DAY,
+    day_09::DAY,
+]);
+
+/// The year this workspace solves
+pub const YEAR: u16 = 2025;
+
+pub fn workspace() -> Workspace {
+    Workspace {
+        year: YEAR,
+        registry: REGISTRY,
+        dir: Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc lives inside the workspace"),
+    }
+}
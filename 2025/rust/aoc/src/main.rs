@@ -0,0 +1,6 @@
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    aoc_runner::run(aoc::workspace())
+}
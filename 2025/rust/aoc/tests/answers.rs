@@ -0,0 +1,8 @@
+//! Runs every registered solution, variants
+//! included, on the local inputs and compares it
+//! with the accepted answer in `answers.toml`.
+
+#[test]
+fn test_known_answers() -> miette::Result<()> {
+    aoc::workspace().check_answers()
+}
@@ -0,0 +1,113 @@
+//! Generates a day from the shared daily template,
+//! the way `just create` does, and runs its tests
+//! before any examples have been scraped.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const DAY: &str = "day-99";
+
+/// The template's placeholders, filled in like
+/// cargo-generate does for `DAY`
+const PLACEHOLDERS: [(&str, &str); 3] = [
+    (
+        "{{project-name | upper_camel_case}}",
+        "Day99",
+    ),
+    ("{{project-name}}", DAY),
+    ("{{crate_name}}", "day_99"),
+];
+
+fn generate(template: &Path, day_dir: &Path) {
+    fs::create_dir_all(day_dir).unwrap();
+    for entry in fs::read_dir(template).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap();
+        let target = day_dir.join(name);
+        if path.is_dir() {
+            generate(&path, &target);
+        } else if name != "cargo-generate.toml" {
+            let mut contents =
+                fs::read_to_string(&path).unwrap();
+            for (placeholder, value) in PLACEHOLDERS {
+                contents =
+                    contents.replace(placeholder, value);
+            }
+            fs::write(target, contents).unwrap();
+        }
+    }
+}
+
+/// A workspace holding only `DAY`, with this year's
+/// dependencies and lockfile
+fn workspace(year_dir: &Path, dir: &Path) {
+    let manifest =
+        fs::read_to_string(year_dir.join("Cargo.toml"))
+            .unwrap();
+    let shared = year_dir.join("../../shared");
+    let dependencies = &manifest[manifest
+        .find("[workspace.dependencies]")
+        .unwrap()..];
+    fs::write(
+        dir.join("Cargo.toml"),
+        format!(
+            "[workspace]\nmembers = [\"{DAY}\"]\nresolver = \"3\"\n\n{}",
+            dependencies.replace(
+                "path = \"../../shared",
+                &format!(
+                    "path = \"{}",
+                    shared.display()
+                ),
+            )
+        ),
+    )
+    .unwrap();
+    let lockfile = year_dir.join("Cargo.lock");
+    if lockfile.exists() {
+        fs::copy(lockfile, dir.join("Cargo.lock")).unwrap();
+    }
+}
+
+#[test]
+fn test_template_without_examples() {
+    let year_dir =
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    // kept between runs, so only the day rebuilds
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("template");
+    let day_dir = dir.join(DAY);
+    if day_dir.exists() {
+        fs::remove_dir_all(&day_dir).unwrap();
+    }
+    generate(
+        &year_dir.join("../../shared/daily-template"),
+        &day_dir,
+    );
+    workspace(&year_dir, &dir);
+    let examples: Vec<_> =
+        fs::read_dir(day_dir.join("examples"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+    assert_eq!(vec![".gitkeep"], examples);
+
+    let output = Command::new(env!("CARGO"))
+        .args(["test", "-p", DAY, "--", "--nocapture"])
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    for part in ["part1", "part2"] {
+        assert!(
+            stderr.contains(&format!(
+                "skipping the {part} examples"
+            )),
+            "{stderr}"
+        );
+    }
+}
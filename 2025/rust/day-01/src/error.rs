@@ -0,0 +1,15 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "counting the times the dial points at 0 \
+         overflowed an i32 at line {line}"
+    )]
+    #[diagnostic(
+        code(day_01::overflow),
+        help("the answer has to fit in 32 bits")
+    )]
+    Overflow { line: usize },
+}
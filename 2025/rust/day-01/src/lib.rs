@@ -1,3 +1,67 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod error;
+pub mod parse;
 pub mod part1;
 pub mod part2;
 pub mod part2_dial_struct;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Parsed = Vec<parse::Direction>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        directions: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(directions)
+    }
+
+    fn part2(
+        directions: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(directions)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+        Solution::new(
+            Part::Two,
+            part2_dial_struct::process,
+        )
+        .variant("dial_struct"),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::variants::check_variants;
+
+    #[test]
+    fn test_variants_agree() -> miette::Result<()> {
+        let input = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+        assert_eq!(
+            "6",
+            check_variants(&DAY, Part::Two, input)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_parse::{IResult, finish, lines};
+use nom::{
+    Parser, branch::alt, character::complete,
+    combinator::verify, error::context, sequence::preceded,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Left(i32),
+    Right(i32),
+}
+
+pub fn parse(
+    input: &str,
+) -> miette::Result<Vec<Direction>> {
+    finish(input, lines(direction))
+}
+
+/// The most clicks a rotation turns, so turning the
+/// dial never overflows an `i32`
+pub const MAX_CLICKS: i32 = 1_000_000_000;
+
+fn direction(input: &str) -> IResult<'_, Direction> {
+    context(
+        "a rotation like `L68` or `R48`",
+        alt((
+            preceded(complete::char('L'), clicks)
+                .map(Direction::Left),
+            preceded(complete::char('R'), clicks)
+                .map(Direction::Right),
+        )),
+    )
+    .parse(input)
+}
+
+fn clicks(input: &str) -> IResult<'_, i32> {
+    context(
+        "at most 1000000000 clicks",
+        verify(complete::i32, |clicks: &i32| {
+            (0..=MAX_CLICKS).contains(clicks)
+        }),
+    )
+    .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_too_many_clicks() {
+        let error = parse("L68\nR1000000001").unwrap_err();
+        assert_eq!(
+            "expected at most 1000000000 clicks at line 2, column 2",
+            error.to_string()
+        );
+        assert!(parse("R1000000000\nL0").is_ok());
+    }
+}
@@ -1,17 +1,15 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    multi::separated_list1,
-    IResult, Parser,
-};
+use crate::parse::{Direction, parse};
 
 const STARTING_POSITION: i32 = 50;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (_, directions) = directions.parse(input).unwrap();
+    solve(&parse(input)?)
+}
 
+pub fn solve(
+    directions: &[Direction],
+) -> miette::Result<String> {
     let (_final_position, counter) =
         directions.iter().fold(
             (STARTING_POSITION, 0),
@@ -31,32 +29,6 @@ pub fn process(input: &str) -> miette::Result<String> {
     Ok(counter.to_string())
 }
 
-#[derive(Debug)]
-enum Direction {
-    Left(i32),
-    Right(i32),
-}
-
-fn directions(
-    input: &str,
-) -> IResult<&str, Vec<Direction>> {
-    separated_list1(line_ending, direction).parse(input)
-}
-
-fn direction(input: &str) -> IResult<&str, Direction> {
-    let (input, dir) =
-        alt((tag("L"), tag("R"))).parse(input)?;
-    let (input, num) = complete::i32(input)?;
-
-    let d = match dir {
-        "L" => Direction::Left(num),
-        "R" => Direction::Right(num),
-        x => panic!("unknown {x}"),
-    };
-
-    Ok((input, d))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,58 +1,36 @@
-use nom::{
-    IResult, Parser,
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    multi::separated_list1,
+use crate::{
+    error::Error,
+    parse::{Direction, parse},
 };
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (_, directions) = directions.parse(input).unwrap();
+    solve(&parse(input)?)
+}
 
+pub fn solve(
+    directions: &[Direction],
+) -> miette::Result<String> {
     let mut dial = 50;
-    let mut counter = 0;
+    let mut counter: i32 = 0;
 
-    for direction in directions {
+    for (index, direction) in directions.iter().enumerate()
+    {
         let num = match direction {
             Direction::Left(num) => -num,
-            Direction::Right(num) => num,
+            Direction::Right(num) => *num,
         };
         let (new_dial, additional_counters) =
             spin(dial, num);
         dial = new_dial;
-        counter += additional_counters;
+        counter = counter
+            .checked_add(additional_counters)
+            .ok_or(Error::Overflow { line: index + 1 })?;
     }
 
     Ok(counter.to_string())
 }
 
-#[derive(Debug)]
-enum Direction {
-    Left(i32),
-    Right(i32),
-}
-
-fn directions(
-    input: &str,
-) -> IResult<&str, Vec<Direction>> {
-    separated_list1(line_ending, direction).parse(input)
-}
-
-fn direction(input: &str) -> IResult<&str, Direction> {
-    let (input, dir) =
-        alt((tag("L"), tag("R"))).parse(input)?;
-    let (input, num) = complete::i32(input)?;
-
-    let d = match dir {
-        "L" => Direction::Left(num),
-        "R" => Direction::Right(num),
-        x => panic!("unknown {x}"),
-    };
-
-    Ok((input, d))
-}
-
 const DIAL_TOTAL: i32 = 100;
 
 fn spin(dial: i32, rot: i32) -> (i32, i32) {
@@ -88,6 +66,17 @@ L82";
         Ok(())
     }
 
+    #[test]
+    fn test_overflow() {
+        // each turn passes 0 ten million times
+        let input = "R1000000000\n".repeat(300);
+        let error = process(&input).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::Overflow { line: 215 })
+        ));
+    }
+
     use rstest::rstest;
 
     #[rstest]
@@ -1,23 +1,18 @@
 use std::ops::Add;
 
-use nom::{
-    IResult, Parser,
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    combinator::all_consuming,
-    multi::separated_list1,
-    sequence::preceded,
+use crate::{
+    error::Error,
+    parse::{Direction, parse},
 };
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (_, directions) =
-        all_consuming(directions).parse(input).unwrap();
-
     let mut dial = Dial::default();
-    for direction in directions {
-        dial.spin(direction);
+    for (index, direction) in
+        parse(input)?.into_iter().enumerate()
+    {
+        dial.spin(direction)
+            .ok_or(Error::Overflow { line: index + 1 })?;
     }
 
     Ok(dial.revolutions.to_string())
@@ -42,12 +37,14 @@ impl Default for Dial {
 }
 
 impl Dial {
+    #[cfg(test)]
     fn new(location: i32) -> Self {
         Self {
             location,
             revolutions: 0,
         }
     }
+    #[cfg(test)]
     fn from_tuple(
         (starting_position, counter): (i32, i32),
     ) -> Self {
@@ -56,7 +53,8 @@ impl Dial {
             revolutions: counter,
         }
     }
-    fn spin(&mut self, rot: Direction) {
+    /// `None` if the revolutions overflow
+    fn spin(&mut self, rot: Direction) -> Option<()> {
         let dial_long = self.location + rot;
         let mut revolutions =
             (dial_long / DIAL_TOTAL).abs();
@@ -66,15 +64,12 @@ impl Dial {
         }
 
         self.location = dial_long.rem_euclid(DIAL_TOTAL);
-        self.revolutions += revolutions;
+        self.revolutions =
+            self.revolutions.checked_add(revolutions)?;
+        Some(())
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Direction {
-    Left(i32),
-    Right(i32),
-}
 impl Add<Direction> for i32 {
     type Output = i32;
 
@@ -86,24 +81,6 @@ impl Add<Direction> for i32 {
     }
 }
 
-fn directions(
-    input: &str,
-) -> IResult<&str, Vec<Direction>> {
-    separated_list1(line_ending, direction).parse(input)
-}
-
-fn direction(input: &str) -> IResult<&str, Direction> {
-    alt((
-        preceded(tag("L"), complete::i32)
-            .map(Direction::Left),
-        preceded(
-            tag("R"),
-            complete::i32.map(Direction::Right),
-        ),
-    ))
-    .parse(input)
-}
-
 const DIAL_TOTAL: i32 = 100;
 
 #[cfg(test)]
@@ -155,7 +132,7 @@ L82";
         #[case] rotation: Direction,
     ) {
         let mut dial = Dial::new(starting_position);
-        dial.spin(rotation);
+        assert_eq!(Some(()), dial.spin(rotation));
         assert_eq!(Dial::from_tuple(expected), dial);
     }
 }
@@ -1,12 +1,18 @@
-use day_02::part2::process;
+use aoc_core::{Part, Solver, input};
+use day_02::Day02;
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let file = input::from_args(
+        env!("CARGO_MANIFEST_DIR"),
+        Part::Two,
+    )?;
+    let result = file
+        .with_source(Day02::run(&file, Part::Two))
+        .context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
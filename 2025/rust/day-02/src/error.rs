@@ -0,0 +1,14 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "the repeated ids up to {end} add up past u128"
+    )]
+    #[diagnostic(
+        code(day_02::overflow),
+        help("the answer has to fit in 128 bits")
+    )]
+    Overflow { end: u64 },
+}
@@ -1,4 +1,70 @@
+use aoc_core::{Day, Part, Solution, Solver};
+use std::ops::RangeInclusive;
+
+pub mod error;
+pub mod parse;
 pub mod part1;
 pub mod part1_ilog;
 pub mod part1_ilog_rayon;
+pub mod part1_scan;
 pub mod part2;
+pub mod part2_scan;
+pub mod repeated;
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Parsed = Vec<RangeInclusive<u64>>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        id_ranges: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(id_ranges)
+    }
+
+    fn part2(
+        id_ranges: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(id_ranges)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::One, part1_ilog::process)
+            .variant("ilog"),
+        Solution::new(Part::One, part1_ilog_rayon::process)
+            .variant("ilog_rayon"),
+        Solution::new(Part::One, part1_scan::process)
+            .variant("scan"),
+        Solution::new(Part::Two, part2::process),
+        Solution::new(Part::Two, part2_scan::process)
+            .variant("scan"),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::variants::check_variants;
+
+    #[test]
+    fn test_variants_agree() -> miette::Result<()> {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        assert_eq!(
+            "1227775554",
+            check_variants(&DAY, Part::One, input)?
+        );
+        assert_eq!(
+            "4174379265",
+            check_variants(&DAY, Part::Two, input)?
+        );
+        Ok(())
+    }
+}
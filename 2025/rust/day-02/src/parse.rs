@@ -0,0 +1,9 @@
+use aoc_parse::{comma_list, finish, range};
+use nom::character::complete;
+use std::ops::RangeInclusive;
+
+pub fn parse(
+    input: &str,
+) -> miette::Result<Vec<RangeInclusive<u64>>> {
+    finish(input, comma_list(range(complete::u64)))
+}
@@ -1,43 +1,32 @@
-use miette::miette;
-use nom::{
-    IResult, Parser, bytes::complete::tag,
-    character::complete, combinator::all_consuming,
-    multi::separated_list1, sequence::separated_pair,
+use crate::{
+    error::Error,
+    parse::parse,
+    repeated::{self, Repeats},
 };
 use std::ops::RangeInclusive;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (_, id_ranges) =
-        all_consuming(ranges).parse(input).map_err(
-            |e| miette!("failed to parse aoc input, {e}"),
-        )?;
-    let mut total = 0;
-    for ids in id_ranges.into_iter() {
-        for id in ids.into_iter() {
-            let id_str = id.to_string();
-            let half = id_str.len() / 2;
-            if &id_str[..half] == &id_str[half..] {
-                total += id;
-            }
-        }
-    }
-    Ok(total.to_string())
+    solve(&parse(input)?)
 }
 
-pub fn ranges(
-    input: &str,
-) -> IResult<&str, Vec<RangeInclusive<u64>>> {
-    separated_list1(
-        tag(","),
-        separated_pair(
-            complete::u64,
-            tag("-"),
-            complete::u64,
-        )
-        .map(|(start, end)| start..=end),
-    )
-    .parse(input)
+pub fn solve(
+    id_ranges: &[RangeInclusive<u64>],
+) -> miette::Result<String> {
+    let total = id_ranges.iter().try_fold(
+        0u128,
+        |total, ids| {
+            repeated::sum(
+                u128::from(*ids.start())
+                    ..=u128::from(*ids.end()),
+                10,
+                Repeats::Twice,
+            )
+            .and_then(|sum| total.checked_add(sum))
+            .ok_or(Error::Overflow { end: *ids.end() })
+        },
+    )?;
+    Ok(total.to_string())
 }
 
 #[cfg(test)]
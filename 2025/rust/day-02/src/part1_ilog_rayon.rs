@@ -1,25 +1,16 @@
-use miette::miette;
-use nom::{
-    IResult, Parser, bytes::complete::tag,
-    character::complete, combinator::all_consuming,
-    multi::separated_list1, sequence::separated_pair,
-};
+use crate::parse::parse;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use std::ops::RangeInclusive;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let (_, id_ranges) =
-        all_consuming(ranges).parse(input).map_err(
-            |e| miette!("failed to parse aoc input, {e}"),
-        )?;
+    let id_ranges = parse(input)?;
     let total = id_ranges
         .into_par_iter()
         .flatten()
         .map(|id| {
             // a number from 0-5, which is half of the
             // number of digits in the number
-            let places = (id.ilog10() + 1) / 2;
+            let places = id.ilog10().div_ceil(2);
             // 10^n, which is 10, 100, 1000, etc
             let hundos = 10u64.pow(places);
             // 204204 == 204 === 204
@@ -29,21 +20,6 @@ pub fn process(input: &str) -> miette::Result<String> {
     Ok(total.to_string())
 }
 
-fn ranges(
-    input: &str,
-) -> IResult<&str, Vec<RangeInclusive<u64>>> {
-    separated_list1(
-        tag(","),
-        separated_pair(
-            complete::u64,
-            tag("-"),
-            complete::u64,
-        )
-        .map(|(start, end)| start..=end),
-    )
-    .parse(input)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,35 @@
+use crate::parse::parse;
+use std::ops::RangeInclusive;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
+}
+
+pub fn solve(
+    id_ranges: &[RangeInclusive<u64>],
+) -> miette::Result<String> {
+    let mut total = 0;
+    for ids in id_ranges.iter() {
+        for id in ids.clone() {
+            let id_str = id.to_string();
+            let half = id_str.len() / 2;
+            if id_str[..half] == id_str[half..] {
+                total += id;
+            }
+        }
+    }
+    Ok(total.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        assert_eq!("1227775554", process(input)?);
+        Ok(())
+    }
+}
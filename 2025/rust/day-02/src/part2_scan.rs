@@ -0,0 +1,57 @@
+use crate::parse::parse;
+use rayon::prelude::*;
+use std::ops::RangeInclusive;
+use tracing::info;
+
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
+}
+
+pub fn solve(
+    id_ranges: &[RangeInclusive<u64>],
+) -> miette::Result<String> {
+    let total = id_ranges
+        .par_iter()
+        .map(|ids| {
+            let mut total = 0;
+            for id in ids.clone() {
+                let id_str = id.to_string();
+                let half = id_str.len() / 2;
+                for limit in 0..half {
+                    if id_str.len().rem_euclid(limit + 1)
+                        == 0
+                    {
+                        let all_match = id_str[0..=limit]
+                            .chars()
+                            .cycle()
+                            .zip(id_str.chars())
+                            .all(|(a, b)| {
+                                info!(?a, ?b);
+                                a == b
+                            });
+                        if all_match {
+                            info!(?id);
+                            total += id;
+                            break;
+                        }
+                    }
+                }
+            }
+            total
+        })
+        .sum::<u64>();
+    Ok(total.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_log::test]
+    fn test_process() -> miette::Result<()> {
+        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+        assert_eq!("4174379265", process(input)?);
+        Ok(())
+    }
+}
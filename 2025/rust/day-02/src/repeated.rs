@@ -0,0 +1,319 @@
+//! Ids made of a block of digits repeated, like `1212`
+//! or `777`. An id of `n` digits repeating a `d` digit
+//! block is the block times the repunit multiplier
+//! `1 + base^d + base^2d + ...`, so the ids in a range
+//! come from a range of blocks, no scanning needed.
+
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Repeats {
+    /// `1212` and `1111`, but not `121212`
+    Twice,
+    /// any number of times, `1212`, `121212` and `111`
+    AtLeastTwice,
+}
+
+/// Every repeated id in `range`, ascending
+pub fn ids(
+    range: RangeInclusive<u128>,
+    base: u32,
+    repeats: Repeats,
+) -> Vec<u128> {
+    let base = check_base(base);
+    let mut ids = Vec::new();
+    for digits in lengths(&range, base) {
+        for block in blocks(digits, repeats) {
+            if let Some((multiplier, blocks)) =
+                series(&range, base, digits, block)
+            {
+                ids.extend(
+                    blocks.map(|block| block * multiplier),
+                );
+            }
+        }
+    }
+    // `111111` repeats `1`, `11` and `111`
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// The sum of the repeated ids in `range`, without
+/// listing them, `None` if it overflows a `u128`
+pub fn sum(
+    range: RangeInclusive<u128>,
+    base: u32,
+    repeats: Repeats,
+) -> Option<u128> {
+    let base = check_base(base);
+    lengths(&range, base).try_fold(
+        0u128,
+        |total, digits| {
+            let sum_of = |block| match series(
+                &range, base, digits, block,
+            ) {
+                Some((multiplier, blocks)) => multiplier
+                    .checked_mul(arithmetic_sum(blocks)?),
+                None => Some(0),
+            };
+            let length_sum = match repeats {
+                Repeats::Twice => {
+                    if digits.is_multiple_of(2) {
+                        sum_of(digits / 2)?
+                    } else {
+                        0
+                    }
+                }
+                // inclusion-exclusion over the blocks: an id
+                // repeating a `d` digit block also repeats
+                // every multiple of `d` that divides `n`.
+                // The added sets, a prime number of repeats,
+                // cover every id, so the answer fits if they
+                // do.
+                Repeats::AtLeastTwice => {
+                    let (mut added, mut removed) =
+                        (0u128, 0u128);
+                    for block in blocks(digits, repeats) {
+                        match mobius(digits / block) {
+                            -1 => {
+                                added = added.checked_add(
+                                    sum_of(block)?,
+                                )?
+                            }
+                            1 => {
+                                removed = removed
+                                    .checked_add(sum_of(
+                                        block,
+                                    )?)?
+                            }
+                            _ => {}
+                        }
+                    }
+                    added - removed
+                }
+            };
+            total.checked_add(length_sum)
+        },
+    )
+}
+
+fn check_base(base: u32) -> u128 {
+    assert!(base >= 2, "base {base} has no digits");
+    u128::from(base)
+}
+
+/// The digit counts of the ids in `range`
+fn lengths(
+    range: &RangeInclusive<u128>,
+    base: u128,
+) -> impl Iterator<Item = u32> {
+    let end = *range.end();
+    (1..).take_while(move |&digits| {
+        base.checked_pow(digits - 1)
+            .is_some_and(|smallest| smallest <= end)
+    })
+}
+
+/// The block lengths an id of `digits` digits can
+/// repeat
+fn blocks(
+    digits: u32,
+    repeats: Repeats,
+) -> impl Iterator<Item = u32> {
+    (1..digits).filter(move |&block| match repeats {
+        Repeats::Twice => block * 2 == digits,
+        Repeats::AtLeastTwice => {
+            digits.is_multiple_of(block)
+        }
+    })
+}
+
+/// The multiplier and the blocks that repeat into
+/// ids of `digits` digits inside `range`
+fn series(
+    range: &RangeInclusive<u128>,
+    base: u128,
+    digits: u32,
+    block: u32,
+) -> Option<(u128, RangeInclusive<u128>)> {
+    // past `u128::MAX` there are no ids to find
+    let multiplier =
+        (0..digits / block).try_fold(0u128, |sum, i| {
+            sum.checked_add(base.checked_pow(i * block)?)
+        })?;
+    // no leading zeros, so the ids have all their
+    // digits
+    let first = base
+        .pow(block - 1)
+        .max(range.start().div_ceil(multiplier));
+    let last =
+        (base.pow(block) - 1).min(range.end() / multiplier);
+    (first <= last).then_some((multiplier, first..=last))
+}
+
+fn arithmetic_sum(
+    range: RangeInclusive<u128>,
+) -> Option<u128> {
+    let (first, last) = range.into_inner();
+    let count = last - first + 1;
+    let ends = first.checked_add(last)?;
+    // one of the two is even
+    if count.is_multiple_of(2) {
+        (count / 2).checked_mul(ends)
+    } else {
+        (ends / 2).checked_mul(count)
+    }
+}
+
+/// The Möbius function, 0 if `n` has a squared prime
+/// factor, otherwise -1 or 1 for an odd or even number
+/// of prime factors
+fn mobius(mut n: u32) -> i8 {
+    let mut result = 1;
+    let mut prime = 2;
+    while prime * prime <= n {
+        if n.is_multiple_of(prime) {
+            n /= prime;
+            if n.is_multiple_of(prime) {
+                return 0;
+            }
+            result = -result;
+        }
+        prime += 1;
+    }
+    if n > 1 { -result } else { result }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// Writes `id` out in `base` and compares the
+    /// blocks
+    fn is_repeated(
+        id: u128,
+        base: u128,
+        repeats: Repeats,
+    ) -> bool {
+        let mut digits = Vec::new();
+        let mut rest = id;
+        while rest > 0 {
+            digits.push(rest % base);
+            rest /= base;
+        }
+        (1..digits.len()).any(|block| {
+            let fits = match repeats {
+                Repeats::Twice => block * 2 == digits.len(),
+                Repeats::AtLeastTwice => {
+                    digits.len() % block == 0
+                }
+            };
+            fits && digits
+                .chunks(block)
+                .all(|chunk| chunk == &digits[..block])
+        })
+    }
+
+    #[rstest]
+    #[case(10, Repeats::Twice)]
+    #[case(10, Repeats::AtLeastTwice)]
+    #[case(2, Repeats::Twice)]
+    #[case(2, Repeats::AtLeastTwice)]
+    #[case(7, Repeats::AtLeastTwice)]
+    fn test_matches_scanning(
+        #[case] base: u32,
+        #[case] repeats: Repeats,
+    ) {
+        let range = 0..=250_000;
+        let expected: Vec<u128> = range
+            .clone()
+            .filter(|&id| {
+                is_repeated(id, u128::from(base), repeats)
+            })
+            .collect();
+        assert_eq!(
+            expected,
+            ids(range.clone(), base, repeats)
+        );
+        assert_eq!(
+            Some(expected.iter().sum::<u128>()),
+            sum(range, base, repeats)
+        );
+    }
+
+    #[rstest]
+    #[case(95..=115, Repeats::Twice, vec![99])]
+    #[case(95..=115, Repeats::AtLeastTwice, vec![99, 111])]
+    #[case(998..=1012, Repeats::AtLeastTwice, vec![999, 1010])]
+    #[case(
+        2121212118..=2121212124,
+        Repeats::AtLeastTwice,
+        vec![2121212121]
+    )]
+    fn test_example_ranges(
+        #[case] range: RangeInclusive<u128>,
+        #[case] repeats: Repeats,
+        #[case] expected: Vec<u128>,
+    ) {
+        assert_eq!(expected, ids(range, 10, repeats));
+    }
+
+    #[test]
+    fn test_wide_range() {
+        let range = 1..=10u128.pow(12);
+        let listed =
+            ids(range.clone(), 10, Repeats::AtLeastTwice);
+        assert_eq!(
+            Some(listed.iter().sum::<u128>()),
+            sum(range, 10, Repeats::AtLeastTwice)
+        );
+        // near the top of u128, 39 digits can't repeat
+        // but 38 can
+        let top = u128::MAX - 10u128.pow(20)..=u128::MAX;
+        assert!(
+            ids(top.clone(), 10, Repeats::Twice).is_empty()
+        );
+        let repunit = (10u128.pow(38) - 1) / 9;
+        assert_eq!(
+            vec![repunit],
+            ids(
+                repunit - 1..=repunit + 1,
+                10,
+                Repeats::AtLeastTwice
+            )
+        );
+    }
+
+    #[rstest]
+    #[case(Repeats::Twice)]
+    #[case(Repeats::AtLeastTwice)]
+    fn test_base_5_near_u128_max(#[case] repeats: Repeats) {
+        // 56 base 5 digits go past u128::MAX, the
+        // repunit multipliers of short blocks don't fit
+        let top = u128::MAX - 10u128.pow(20)..=u128::MAX;
+        let listed = ids(top.clone(), 5, repeats);
+        assert!(listed.len() > 1);
+        assert!(
+            listed
+                .iter()
+                .all(|&id| { is_repeated(id, 5, repeats) })
+        );
+        // two ids this big add up past u128::MAX
+        assert_eq!(None, sum(top, 5, repeats));
+        let one = listed[0] - 1..=listed[0] + 1;
+        assert_eq!(Some(listed[0]), sum(one, 5, repeats));
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(2, -1)]
+    #[case(4, 0)]
+    #[case(6, 1)]
+    #[case(30, -1)]
+    #[case(97, -1)]
+    fn test_mobius(#[case] n: u32, #[case] expected: i8) {
+        assert_eq!(expected, mobius(n));
+    }
+}
@@ -1,12 +1,18 @@
-use day_03::part1::process;
+use aoc_core::{Part, Solver, input};
+use day_03::Day03;
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let file = input::from_args(
+        env!("CARGO_MANIFEST_DIR"),
+        Part::One,
+    )?;
+    let result = file
+        .with_source(Day03::run(&file, Part::One))
+        .context("process part 1")?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
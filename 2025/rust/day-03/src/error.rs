@@ -0,0 +1,61 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "bank {bank} has {len} batteries, {needed} have \
+         to be turned on"
+    )]
+    #[diagnostic(
+        code(day_03::bank_too_short),
+        help("every line of the input is a whole bank")
+    )]
+    BankTooShort {
+        /// 1-based, the line of the input
+        bank: usize,
+        len: usize,
+        needed: usize,
+    },
+
+    #[error("{len} batteries can't turn on {needed}")]
+    #[diagnostic(code(day_03::too_few_batteries))]
+    TooFewBatteries { len: usize, needed: usize },
+
+    #[error("{needed} batteries don't fit a u128 joltage")]
+    #[diagnostic(
+        code(day_03::too_many_digits),
+        help("at most {MAX_DIGITS} batteries fit")
+    )]
+    TooManyDigits { needed: usize },
+
+    #[error(
+        "the joltage of {banks} banks overflows a u128"
+    )]
+    #[diagnostic(
+        code(day_03::overflow),
+        help("turn on fewer batteries per bank")
+    )]
+    Overflow { banks: usize },
+}
+
+/// Any 38 digit joltage fits a `u128`, not every 39
+/// digit one does
+pub const MAX_DIGITS: usize = 38;
+
+/// Fails unless every bank can turn on `needed`
+/// batteries
+pub fn check_banks(
+    banks: &[Vec<u8>],
+    needed: usize,
+) -> Result<(), Error> {
+    match banks.iter().position(|bank| bank.len() < needed)
+    {
+        Some(index) => Err(Error::BankTooShort {
+            bank: index + 1,
+            len: banks[index].len(),
+            needed,
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,164 @@
+use crate::error::{Error, MAX_DIGITS, check_banks};
+
+/// The largest number a bank makes by turning on some of
+/// its batteries, read in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Joltage {
+    pub value: u128,
+    /// ascending, into the bank
+    pub indices: Vec<usize>,
+}
+
+/// Turns on the `k` batteries making the largest joltage.
+/// A battery knocks every smaller one before it off a
+/// stack while batteries are left to spare, which picks
+/// the largest first digit, then the largest second and
+/// so on, in one pass.
+pub fn max_joltage(
+    bank: &[u8],
+    k: usize,
+) -> Result<Joltage, Error> {
+    if bank.len() < k {
+        return Err(Error::TooFewBatteries {
+            len: bank.len(),
+            needed: k,
+        });
+    }
+    if k > MAX_DIGITS {
+        return Err(Error::TooManyDigits { needed: k });
+    }
+    let mut spare = bank.len() - k;
+    let mut indices: Vec<usize> =
+        Vec::with_capacity(bank.len());
+    for (index, &battery) in bank.iter().enumerate() {
+        while spare > 0
+            && indices
+                .last()
+                .is_some_and(|&last| bank[last] < battery)
+        {
+            indices.pop();
+            spare -= 1;
+        }
+        indices.push(index);
+    }
+    // the spare batteries left over are at the end
+    indices.truncate(k);
+    let value = indices.iter().fold(0, |value, &index| {
+        value * 10 + u128::from(bank[index])
+    });
+    Ok(Joltage { value, indices })
+}
+
+/// The sum of every bank's [`max_joltage`]
+pub fn total_joltage(
+    banks: &[Vec<u8>],
+    k: usize,
+) -> Result<u128, Error> {
+    check_banks(banks, k)?;
+    banks.iter().try_fold(0u128, |total, bank| {
+        total
+            .checked_add(max_joltage(bank, k)?.value)
+            .ok_or(Error::Overflow { banks: banks.len() })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn bank(digits: &str) -> Vec<u8> {
+        digits.bytes().map(|digit| digit - b'0').collect()
+    }
+
+    #[rstest]
+    #[case("987654321111111", 2, 98)]
+    #[case("811111111111119", 2, 89)]
+    #[case("234234234234278", 12, 434234234278)]
+    #[case("818181911112111", 12, 888911112111)]
+    #[case("12345", 5, 12345)]
+    #[case("12345", 0, 0)]
+    fn test_max_joltage(
+        #[case] digits: &str,
+        #[case] k: usize,
+        #[case] expected: u128,
+    ) {
+        assert_eq!(
+            expected,
+            max_joltage(&bank(digits), k).unwrap().value
+        );
+    }
+
+    #[test]
+    fn test_indices() {
+        let joltage =
+            max_joltage(&bank("811111111111119"), 2)
+                .unwrap();
+        assert_eq!(vec![0, 14], joltage.indices);
+        // ties keep the earliest battery
+        let joltage =
+            max_joltage(&bank("9899"), 2).unwrap();
+        assert_eq!(99, joltage.value);
+        assert_eq!(vec![0, 2], joltage.indices);
+    }
+
+    /// Tries every choice of `k` batteries
+    fn brute_force(bank: &[u8], k: usize) -> u128 {
+        (0..1u32 << bank.len())
+            .filter(|mask| mask.count_ones() as usize == k)
+            .map(|mask| {
+                (0..bank.len())
+                    .filter(|i| mask & 1 << i != 0)
+                    .fold(0, |value, i| {
+                        value * 10 + u128::from(bank[i])
+                    })
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let bank = bank("3141592653589793");
+        for k in 0..=bank.len() {
+            assert_eq!(
+                brute_force(&bank, k),
+                max_joltage(&bank, k).unwrap().value,
+                "{k}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_total_overflow() {
+        let banks = vec![bank(&"9".repeat(38)); 4];
+        assert_eq!(
+            3 * (10u128.pow(38) - 1),
+            total_joltage(&banks[..3], 38).unwrap()
+        );
+        assert!(matches!(
+            total_joltage(&banks, 38),
+            Err(Error::Overflow { banks: 4 })
+        ));
+    }
+
+    #[test]
+    fn test_wide_joltage() {
+        let bank = bank(&"9".repeat(40));
+        assert_eq!(
+            10u128.pow(38) - 1,
+            max_joltage(&bank, 38).unwrap().value
+        );
+        assert!(matches!(
+            max_joltage(&bank, 39),
+            Err(Error::TooManyDigits { needed: 39 })
+        ));
+        assert!(matches!(
+            max_joltage(&bank, 41),
+            Err(Error::TooFewBatteries {
+                len: 40,
+                needed: 41
+            })
+        ));
+    }
+}
@@ -1,3 +1,59 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod error;
+pub mod joltage;
+pub mod parse;
 pub mod part1;
 pub mod part2;
 pub mod part2_reduce;
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Parsed = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        banks: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(banks)
+    }
+
+    fn part2(
+        banks: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(banks)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+        Solution::new(Part::Two, part2_reduce::process)
+            .variant("reduce"),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::variants::check_variants;
+
+    #[test]
+    fn test_variants_agree() -> miette::Result<()> {
+        let input = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+        assert_eq!(
+            "3121910778619",
+            check_variants(&DAY, Part::Two, input)?
+        );
+        Ok(())
+    }
+}
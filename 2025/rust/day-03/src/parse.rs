@@ -0,0 +1,25 @@
+use aoc_parse::Diagnostic;
+use nom::Offset;
+
+/// Each line is a bank of batteries, each digit a
+/// battery's joltage
+pub fn parse(input: &str) -> miette::Result<Vec<Vec<u8>>> {
+    input
+        .lines()
+        .map(|bank| {
+            let start = input.offset(bank);
+            bank.bytes()
+                .enumerate()
+                .map(|(i, battery)| match battery {
+                    b'1'..=b'9' => Ok(battery - b'0'),
+                    _ => Err(Diagnostic::new(
+                        input,
+                        start + i..start + i + 1,
+                        "a joltage from 1-9",
+                    )
+                    .into()),
+                })
+                .collect()
+        })
+        .collect()
+}
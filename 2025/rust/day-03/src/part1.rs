@@ -1,43 +1,18 @@
-use itertools::Itertools;
-use tracing::info;
+use crate::{joltage::total_joltage, parse::parse};
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let result = input
-        .lines()
-        .map(|bank| {
-            let (index, first_max) = &bank
-                [..(bank.len() - 1)]
-                .chars()
-                .enumerate()
-                .max_set_by_key(|(_index, battery)| {
-                    *battery
-                })
-                .first()
-                .cloned()
-                .unwrap();
-
-            debug_assert!(!bank[(index + 1)..].is_empty());
-
-            let (_second_index, second_max) = &bank
-                [(index + 1)..]
-                .chars()
-                .enumerate()
-                .max_by_key(|(_index, battery)| *battery)
-                .unwrap();
-
-            format!("{first_max}{second_max}")
-                .parse::<u64>()
-                .unwrap()
-        })
-        .sum::<u64>();
+    solve(&parse(input)?)
+}
 
-    Ok(result.to_string())
+pub fn solve(banks: &[Vec<u8>]) -> miette::Result<String> {
+    Ok(total_joltage(banks, 2)?.to_string())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
 
     #[test_log::test]
     fn test_process() -> miette::Result<()> {
@@ -49,4 +24,17 @@ mod tests {
         assert_eq!("357", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_bank_too_short() {
+        let error = process("987\n8").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::BankTooShort {
+                bank: 2,
+                len: 1,
+                needed: 2
+            })
+        ));
+    }
 }
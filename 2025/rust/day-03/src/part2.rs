@@ -1,47 +1,12 @@
-use itertools::Itertools;
+use crate::{joltage::total_joltage, parse::parse};
 
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<String> {
-    let result = input
-        .lines()
-        .map(|bank| {
-            let mut batteries: Vec<char> = vec![];
-
-            let mut current_index = 0;
-            for i in 0..11 {
-                let (index, first_max) = &bank
-                    [current_index..(bank.len() - 11 + i)]
-                    .chars()
-                    .enumerate()
-                    .max_set_by_key(|(_index, battery)| {
-                        *battery
-                    })
-                    .first()
-                    .cloned()
-                    .unwrap();
-
-                batteries.push(*first_max);
-                current_index = current_index + index + 1;
-            }
-
-            let (_second_index, second_max) = &bank
-                [(current_index)..]
-                .chars()
-                .enumerate()
-                .max_by_key(|(_index, battery)| *battery)
-                .unwrap();
-
-            batteries.push(*second_max);
-
-            batteries
-                .iter()
-                .collect::<String>()
-                .parse::<u64>()
-                .unwrap()
-        })
-        .sum::<u64>();
+    solve(&parse(input)?)
+}
 
-    Ok(result.to_string())
+pub fn solve(banks: &[Vec<u8>]) -> miette::Result<String> {
+    Ok(total_joltage(banks, 12)?.to_string())
 }
 
 #[cfg(test)]
@@ -1,16 +1,19 @@
+use crate::{error::check_banks, parse::parse};
+
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<String> {
-    let result = input
-        .lines()
+    let banks = parse(input)?;
+    check_banks(&banks, 12)?;
+    let result = banks
+        .iter()
         .map(|bank| {
-            let mut batteries: String =
-                String::with_capacity(12);
+            let mut joltage = 0u64;
 
             let mut current_index = 0;
             for i in 0..12 {
-                let (index, first_max) = &bank
+                let (index, first_max) = bank
                     [current_index..(bank.len() - 11 + i)]
-                    .chars()
+                    .iter()
                     .enumerate()
                     .reduce(|acc, next| {
                         if next.1 > acc.1 {
@@ -19,13 +22,14 @@ pub fn process(input: &str) -> miette::Result<String> {
                             acc
                         }
                     })
-                    .unwrap();
+                    .expect("enough batteries are left");
 
-                batteries.push(*first_max);
+                joltage =
+                    joltage * 10 + u64::from(*first_max);
                 current_index = current_index + index + 1;
             }
 
-            batteries.parse::<u64>().unwrap()
+            joltage
         })
         .sum::<u64>();
 
@@ -1,45 +1,80 @@
+use aoc_core::{Part, Solver, input};
 use day_04::*;
+use std::sync::LazyLock;
+
+static INPUT1: LazyLock<String> = LazyLock::new(|| {
+    input::for_bench(env!("CARGO_MANIFEST_DIR"), Part::One)
+});
+
+static INPUT2: LazyLock<String> = LazyLock::new(|| {
+    input::for_bench(env!("CARGO_MANIFEST_DIR"), Part::Two)
+});
+
+static PARSED1: LazyLock<<Day04 as Solver>::Parsed> =
+    LazyLock::new(|| Day04::parse(&INPUT1).unwrap());
+
+static PARSED2: LazyLock<<Day04 as Solver>::Parsed> =
+    LazyLock::new(|| Day04::parse(&INPUT2).unwrap());
 
 fn main() {
+    // Read inputs before anything is timed
+    input::bench_divan(
+        env!("CARGO_MANIFEST_DIR"),
+        |part| {
+            match part {
+                Part::One => LazyLock::force(&PARSED1),
+                Part::Two => LazyLock::force(&PARSED2),
+            };
+        },
+    )
     // Run registered benchmarks.
-    divan::main();
+    .main();
 }
 
 #[divan::bench]
 fn parse() {
-    part1::parse(divan::black_box(include_str!(
-        "../input1.txt"
-    )));
+    Day04::parse(divan::black_box(&INPUT1)).unwrap();
 }
 
 #[divan::bench]
 fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt"
-    )))
-    .unwrap();
+    part1::process(divan::black_box(&INPUT1)).unwrap();
 }
 
 #[divan::bench]
 fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt"
-    )))
-    .unwrap();
+    part2::process(divan::black_box(&INPUT2)).unwrap();
+}
+
+#[divan::bench]
+fn part2_bit_grid() {
+    part2_bit_grid::process(divan::black_box(&INPUT2))
+        .unwrap();
 }
 
 #[divan::bench]
 fn part2_rustc_hash() {
-    part2_rustc_hash::process(divan::black_box(
-        include_str!("../input2.txt"),
-    ))
-    .unwrap();
+    part2_rustc_hash::process(divan::black_box(&INPUT2))
+        .unwrap();
 }
 
 #[divan::bench]
 fn part2_convolve() {
-    part2_convolve::process(divan::black_box(
-        include_str!("../input2.txt"),
-    ))
-    .unwrap();
+    part2_convolve::process(divan::black_box(&INPUT2))
+        .unwrap();
+}
+
+/// `part1` and `part2` on already parsed input
+mod solve {
+    use super::*;
+
+    #[divan::bench]
+    fn part1() {
+        Day04::part1(divan::black_box(&PARSED1)).unwrap();
+    }
+
+    #[divan::bench]
+    fn part2() {
+        Day04::part2(divan::black_box(&PARSED2)).unwrap();
+    }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Part, input};
 use day_04::part2_convolve::process;
 use miette::Context;
 
@@ -5,8 +6,13 @@ use miette::Context;
 fn main() -> miette::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let file = input::from_args(
+        env!("CARGO_MANIFEST_DIR"),
+        Part::Two,
+    )?;
+    let result = file
+        .with_source(process(&file))
+        .context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
@@ -1,4 +1,79 @@
+use aoc_automaton::Rule;
+use aoc_core::{Day, Part, Solution, Solver};
+use aoc_grid::NEIGHBORS;
+use glam::IVec2;
+use std::collections::HashSet;
+
+pub mod parse;
 pub mod part1;
 pub mod part2;
+pub mod part2_bit_grid;
 pub mod part2_convolve;
 pub mod part2_rustc_hash;
+
+/// A roll stays while at least 4 of the 8 positions
+/// around it hold rolls, the forklifts can't get to it
+pub fn rule() -> Rule {
+    Rule::new(&NEIGHBORS).survive(4..=8)
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Parsed = HashSet<IVec2>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        positions: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(positions)
+    }
+
+    fn part2(
+        positions: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(positions)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+        Solution::new(Part::Two, part2_bit_grid::process)
+            .variant("bit_grid"),
+        Solution::new(Part::Two, part2_convolve::process)
+            .variant("convolve"),
+        Solution::new(Part::Two, part2_rustc_hash::process)
+            .variant("rustc_hash"),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::variants::check_variants;
+
+    #[test]
+    fn test_variants_agree() -> miette::Result<()> {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        assert_eq!(
+            "43",
+            check_variants(&DAY, Part::Two, input)?
+        );
+        Ok(())
+    }
+}
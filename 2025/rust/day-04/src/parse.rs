@@ -0,0 +1,24 @@
+use aoc_grid::Grid;
+use aoc_parse::{finish, grid};
+use glam::IVec2;
+use nom::{Parser, character::complete::one_of};
+
+/// The map, `true` where there's a roll of paper, `@`
+pub fn rolls(input: &str) -> miette::Result<Grid<bool>> {
+    finish(
+        input,
+        grid(one_of(".@").map(|cell| cell == '@')),
+    )
+}
+
+/// Positions of every roll of paper, in whichever
+/// set the caller wants
+pub fn parse<S: FromIterator<IVec2>>(
+    input: &str,
+) -> miette::Result<S> {
+    Ok(rolls(input)?
+        .iter()
+        .filter(|&(_, &roll)| roll)
+        .map(|(position, _)| position)
+        .collect())
+}
@@ -1,52 +1,20 @@
-use std::collections::HashSet;
-
+use crate::{parse::parse, rule};
+use aoc_automaton::Automaton;
 use glam::IVec2;
-use tracing::info;
-
-const NEIGHBORS: [IVec2; 8] = [
-    IVec2::X,
-    IVec2::Y,
-    IVec2::NEG_X,
-    IVec2::NEG_Y,
-    IVec2::ONE,
-    IVec2::NEG_ONE,
-    IVec2::new(1, -1),
-    IVec2::new(-1, 1),
-];
+use std::collections::HashSet;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let positions = parse(input);
-
-    let count = positions
-        .iter()
-        .filter(|&position| {
-            NEIGHBORS
-                .iter()
-                .filter(|&offset| {
-                    positions.contains(&(position + offset))
-                })
-                .count()
-                < 4
-        })
-        .count();
-    Ok(count.to_string())
+    solve(&parse(input)?)
 }
 
-pub fn parse(input: &str) -> HashSet<IVec2> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars().enumerate().filter_map(
-                move |(x, value)| {
-                    (value == '@').then_some(IVec2::new(
-                        x as i32, y as i32,
-                    ))
-                },
-            )
-        })
-        .collect::<HashSet<IVec2>>()
+/// The rolls the first generation removes
+pub fn solve(
+    positions: &HashSet<IVec2>,
+) -> miette::Result<String> {
+    let mut automaton =
+        Automaton::new(rule(), positions.clone());
+    Ok(automaton.step().died.to_string())
 }
 
 #[cfg(test)]
@@ -0,0 +1,32 @@
+use crate::{parse::rolls, rule};
+use aoc_automaton::{Automaton, BitGrid};
+
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> miette::Result<String> {
+    let rolls = BitGrid::from(&rolls(input)?);
+    let mut automaton = Automaton::new(rule(), rolls);
+    let removed_count: usize =
+        automaton.run().iter().map(|step| step.died).sum();
+    Ok(removed_count.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test_log::test]
+    fn test_process() -> miette::Result<()> {
+        let input = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+        assert_eq!("43", process(input)?);
+        Ok(())
+    }
+}
@@ -1,58 +1,60 @@
-use ndarray::prelude::*;
-use num_traits::Float;
-
-const PAPER: [[f32; 3]; 3] =
-    [[1., 1., 1.], [1., 1., 1.], [1., 1., 1.]];
-
-type Kernel3x3<A> = [[A; 3]; 3];
+use crate::{parse::rolls, rule};
+use aoc_automaton::Rule;
+use aoc_grid::{Border, Grid};
+use glam::IVec2;
 
+/// Steps `rule()` a whole grid at a time, the live
+/// neighbours of every cell are one convolution
 #[tracing::instrument(skip(input))]
 pub fn process(input: &str) -> miette::Result<String> {
-    let n = input.lines().count() + 2;
-    let n2 = input.lines().next().unwrap().len();
-    // assuming a square input
-    debug_assert!(n == n2);
-
-    let mut a = Array::zeros((n, n));
+    let rule = rule();
+    let mut rolls = rolls(input)?;
+    let total = count(&rolls);
+    let kernel = kernel(&rule);
 
-    let mut total = 0;
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, value) in line.chars().enumerate() {
-            if value == '@' {
-                a[[x + 1, y + 1]] = 1.;
-                total += 1;
-            }
-        }
-    }
-
-    let mut last_res = Array::zeros(a.dim());
     loop {
-        let mut res = Array::zeros(a.dim());
-        conv_3x3(&a.view(), &mut res.view_mut(), &PAPER);
-        if res == last_res {
+        let neighbors = rolls
+            .convolve::<bool, u8>(&kernel, Border::Zero);
+        let next = Grid::from_vec(
+            rolls.width(),
+            rolls
+                .iter()
+                .map(|(position, &roll)| {
+                    rule.next(
+                        roll,
+                        usize::from(neighbors[position]),
+                    )
+                })
+                .collect(),
+        );
+        if next == rolls {
             break;
         }
-        for ((_, value), (_, current)) in
-            res.indexed_iter().zip(a.indexed_iter_mut())
-        {
-            // the convolution counts *all* positions, not just
-            // the neighbors. That includes the current roll which
-            // increases the "4" limit from the problem to "5"
-            if *value < 5. {
-                *current = 0.;
-            }
-        }
-        last_res = res;
+        rolls = next;
     }
-    let result =
-        a.iter()
-            .filter(|float| {
-                if **float < 0.5 { false } else { true }
-            })
-            .count();
 
-    Ok((total - result).to_string())
+    Ok((total - count(&rolls)).to_string())
+}
+
+/// `true` at the offsets of the rule's neighbourhood
+/// from the centre
+fn kernel(rule: &Rule) -> Grid<bool> {
+    let radius = rule
+        .neighborhood()
+        .iter()
+        .map(|offset| offset.abs().max_element())
+        .max()
+        .unwrap_or(0);
+    let side = 2 * radius as usize + 1;
+    let mut kernel = Grid::new(side, side, false);
+    for &offset in rule.neighborhood() {
+        kernel[IVec2::splat(radius) + offset] = true;
+    }
+    kernel
+}
+
+fn count(rolls: &Grid<bool>) -> usize {
+    rolls.iter().filter(|&(_, &roll)| roll).count()
 }
 
 #[cfg(test)]
@@ -74,38 +76,22 @@ mod tests {
         assert_eq!("43", process(input)?);
         Ok(())
     }
-}
 
-#[inline(never)]
-fn conv_3x3<F>(
-    a: &ArrayRef2<F>,
-    out: &mut ArrayRef2<F>,
-    kernel: &Kernel3x3<F>,
-) where
-    F: Float,
-{
-    let (n, m) = a.dim();
-    let (np, mp) = out.dim();
-    if n < 3 || m < 3 {
-        return;
+    #[test]
+    fn test_kernel() {
+        // the 8 neighbours, not the cell itself
+        let mut expected = Grid::new(3, 3, true);
+        expected[IVec2::ONE] = false;
+        assert_eq!(expected, kernel(&rule()));
     }
-    assert!(np >= n && mp >= m);
-    // i, j offset by -1 so that we can use unsigned indices
-    unsafe {
-        for i in 0..n - 2 {
-            for j in 0..m - 2 {
-                let mut conv = F::zero();
-                #[allow(clippy::needless_range_loop)]
-                for k in 0..3 {
-                    for l in 0..3 {
-                        conv = conv
-                            + *a.uget((i + k, j + l))
-                                * kernel[k][l];
-                        //conv += a[[i + k, j + l]] * x_kernel[k][l];
-                    }
-                }
-                *out.uget_mut((i + 1, j + 1)) = conv;
-            }
-        }
+
+    #[test]
+    fn test_wide_map() -> miette::Result<()> {
+        let input = "@@@@@\n@@.@@\n@@@@@";
+        assert_eq!(
+            crate::part2::process(input)?,
+            process(input)?
+        );
+        Ok(())
     }
 }
@@ -1,22 +1,62 @@
+use aoc_core::{Part, Solver, input};
 use day_05::*;
+use std::sync::LazyLock;
+
+static INPUT1: LazyLock<String> = LazyLock::new(|| {
+    input::for_bench(env!("CARGO_MANIFEST_DIR"), Part::One)
+});
+
+static INPUT2: LazyLock<String> = LazyLock::new(|| {
+    input::for_bench(env!("CARGO_MANIFEST_DIR"), Part::Two)
+});
+
+static PARSED1: LazyLock<<Day05 as Solver>::Parsed> =
+    LazyLock::new(|| Day05::parse(&INPUT1).unwrap());
+
+static PARSED2: LazyLock<<Day05 as Solver>::Parsed> =
+    LazyLock::new(|| Day05::parse(&INPUT2).unwrap());
 
 fn main() {
+    // Read inputs before anything is timed
+    input::bench_divan(
+        env!("CARGO_MANIFEST_DIR"),
+        |part| {
+            match part {
+                Part::One => LazyLock::force(&PARSED1),
+                Part::Two => LazyLock::force(&PARSED2),
+            };
+        },
+    )
     // Run registered benchmarks.
-    divan::main();
+    .main();
+}
+
+#[divan::bench]
+fn parse() {
+    Day05::parse(divan::black_box(&INPUT1)).unwrap();
 }
 
 #[divan::bench]
 fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+    part1::process(divan::black_box(&INPUT1)).unwrap();
 }
 
 #[divan::bench]
 fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+    part2::process(divan::black_box(&INPUT2)).unwrap();
+}
+
+/// `part1` and `part2` on already parsed input
+mod solve {
+    use super::*;
+
+    #[divan::bench]
+    fn part1() {
+        Day05::part1(divan::black_box(&PARSED1)).unwrap();
+    }
+
+    #[divan::bench]
+    fn part2() {
+        Day05::part2(divan::black_box(&PARSED2)).unwrap();
+    }
+}
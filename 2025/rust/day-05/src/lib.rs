@@ -1,2 +1,35 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Parsed = parse::Inventory;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        inventory: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(inventory)
+    }
+
+    fn part2(
+        inventory: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(inventory)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+    ],
+};
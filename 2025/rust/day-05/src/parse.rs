@@ -0,0 +1,37 @@
+use aoc_interval_set::IntervalSet;
+use aoc_parse::{blank_line, finish, lines, range};
+use miette::Result;
+use nom::{
+    Parser,
+    character::complete,
+    combinator::opt,
+    sequence::{pair, preceded},
+};
+
+#[derive(Debug, Clone)]
+pub struct Inventory {
+    /// the fresh ingredient ids, overlapping ranges
+    /// merged
+    pub fresh_ingredients: IntervalSet<i64>,
+    /// the available ingredient ids, after the
+    /// blank line. Part 2 doesn't need them, so they
+    /// can be left out.
+    pub ingredients: Vec<i64>,
+}
+
+pub fn parse(input: &str) -> Result<Inventory> {
+    finish(
+        input,
+        pair(
+            lines(range(complete::i64)),
+            opt(preceded(
+                blank_line,
+                lines(complete::i64),
+            )),
+        )
+        .map(|(ranges, ingredients)| Inventory {
+            fresh_ingredients: ranges.into_iter().collect(),
+            ingredients: ingredients.unwrap_or_default(),
+        }),
+    )
+}
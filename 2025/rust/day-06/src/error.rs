@@ -0,0 +1,32 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "the worksheet has {columns} columns of numbers \
+         but {operators} operators"
+    )]
+    #[diagnostic(
+        code(day_06::operator_count),
+        help(
+            "every column needs an operator on the last line"
+        )
+    )]
+    OperatorCount { columns: usize, operators: usize },
+
+    #[error("`{operator}` has no numbers to combine")]
+    #[diagnostic(code(day_06::empty_problem))]
+    EmptyProblem { operator: char },
+
+    #[error("dividing by zero")]
+    #[diagnostic(code(day_06::division_by_zero))]
+    DivisionByZero,
+
+    #[error("`{operator}` overflowed an i128")]
+    #[diagnostic(
+        code(day_06::overflow),
+        help("the answers have to fit in 128 bits")
+    )]
+    Overflow { operator: char },
+}
@@ -1,2 +1,37 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod error;
+pub mod operator;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Parsed = parse::Worksheet;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        worksheet: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(&worksheet.rows())
+    }
+
+    fn part2(
+        worksheet: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(&worksheet.columns())
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+    ],
+};
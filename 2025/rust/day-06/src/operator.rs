@@ -0,0 +1,183 @@
+use crate::{error::Error, parse::Problem};
+use aoc_parse::IResult;
+use nom::{
+    Parser, character::complete::anychar,
+    combinator::map_opt, error::context,
+};
+
+/// What combines a problem's numbers, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operators {
+    Plus,
+    /// the first minus all the others
+    Minus,
+    Times,
+    /// the first divided by all the others, rounding
+    /// towards zero
+    Divide,
+    Min,
+    Max,
+    /// the digits written one after another
+    Concat,
+}
+
+impl Operators {
+    /// Every operator with its symbol on the worksheet,
+    /// add a row here to teach the parser a new one
+    pub const TABLE: [(char, Self); 7] = [
+        ('+', Self::Plus),
+        ('-', Self::Minus),
+        ('*', Self::Times),
+        ('/', Self::Divide),
+        ('<', Self::Min),
+        ('>', Self::Max),
+        ('|', Self::Concat),
+    ];
+
+    pub fn symbol(self) -> char {
+        Self::TABLE
+            .iter()
+            .find(|(_, operator)| *operator == self)
+            .map(|(symbol, _)| *symbol)
+            .expect("every operator is in the table")
+    }
+
+    pub(crate) fn parse(input: &str) -> IResult<'_, Self> {
+        context(
+            "an operator, one of `+ - * / < > |`",
+            map_opt(anychar, |symbol| {
+                Self::TABLE
+                    .iter()
+                    .find(|(known, _)| *known == symbol)
+                    .map(|(_, operator)| *operator)
+            }),
+        )
+        .parse(input)
+    }
+
+    /// Folds `numbers` left to right in `i128`, failing
+    /// instead of overflowing
+    pub fn apply(
+        self,
+        numbers: &[i64],
+    ) -> Result<i128, Error> {
+        let (&first, rest) = numbers.split_first().ok_or(
+            Error::EmptyProblem {
+                operator: self.symbol(),
+            },
+        )?;
+        rest.iter().try_fold(
+            i128::from(first),
+            |result, &number| {
+                let number = i128::from(number);
+                match self {
+                    Self::Plus => {
+                        result.checked_add(number)
+                    }
+                    Self::Minus => {
+                        result.checked_sub(number)
+                    }
+                    Self::Times => {
+                        result.checked_mul(number)
+                    }
+                    Self::Divide => {
+                        if number == 0 {
+                            return Err(
+                                Error::DivisionByZero,
+                            );
+                        }
+                        result.checked_div(number)
+                    }
+                    Self::Min => Some(result.min(number)),
+                    Self::Max => Some(result.max(number)),
+                    Self::Concat => concat(result, number),
+                }
+                .ok_or(Error::Overflow {
+                    operator: self.symbol(),
+                })
+            },
+        )
+    }
+}
+
+/// `a` followed by the digits of `b`, `None` if it
+/// doesn't fit or either is negative
+fn concat(a: i128, b: i128) -> Option<i128> {
+    if a < 0 || b < 0 {
+        return None;
+    }
+    let digits = b.checked_ilog10().unwrap_or(0) + 1;
+    a.checked_mul(10i128.checked_pow(digits)?)?
+        .checked_add(b)
+}
+
+/// The sum of every problem's answer
+pub fn grand_total(
+    problems: &[Problem],
+) -> Result<i128, Error> {
+    problems.iter().try_fold(0i128, |total, problem| {
+        total
+            .checked_add(
+                problem.operator.apply(&problem.numbers)?,
+            )
+            .ok_or(Error::Overflow { operator: '+' })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case('+', &[123, 45, 6], 174)]
+    #[case('-', &[100, 30, 5], 65)]
+    #[case('*', &[123, 45, 6], 33210)]
+    #[case('/', &[100, 3, 2], 16)]
+    #[case('<', &[51, 387, 215], 51)]
+    #[case('>', &[51, 387, 215], 387)]
+    #[case('|', &[12, 0, 345], 120345)]
+    #[case('*', &[7], 7)]
+    fn test_apply(
+        #[case] symbol: char,
+        #[case] numbers: &[i64],
+        #[case] expected: i128,
+    ) {
+        let (_, operator) =
+            Operators::parse(&symbol.to_string()).unwrap();
+        assert_eq!(symbol, operator.symbol());
+        assert_eq!(
+            expected,
+            operator.apply(numbers).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_past_i64() {
+        // overflows an i64 but not an i128
+        assert_eq!(
+            i128::from(i64::MAX) * 2,
+            Operators::Times.apply(&[i64::MAX, 2]).unwrap()
+        );
+        assert!(matches!(
+            Operators::Times.apply(&[i64::MAX; 3]),
+            Err(Error::Overflow { operator: '*' })
+        ));
+        assert!(matches!(
+            Operators::Concat.apply(&[i64::MAX; 3]),
+            Err(Error::Overflow { operator: '|' })
+        ));
+    }
+
+    #[test]
+    fn test_bad_problems() {
+        assert!(matches!(
+            Operators::Plus.apply(&[]),
+            Err(Error::EmptyProblem { operator: '+' })
+        ));
+        assert!(matches!(
+            Operators::Divide.apply(&[1, 0]),
+            Err(Error::DivisionByZero)
+        ));
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{error::Error, operator::Operators};
+use aoc_parse::Diagnostic;
+use nom::Offset;
+use std::ops::Range;
+
+/// The most digits an `i64` always holds
+const MAX_DIGITS: usize = 18;
+
+/// A single column of the worksheet, the numbers
+/// above the operator that combines them
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub numbers: Vec<i64>,
+    pub operator: Operators,
+}
+
+/// The worksheet as it was laid out. A problem is a run
+/// of columns between columns of spaces, its operator
+/// sits on the last line below it.
+#[derive(Debug, Clone)]
+pub struct Worksheet {
+    /// the lines of numbers, padded with spaces to the
+    /// same width
+    lines: Vec<Vec<u8>>,
+    problems: Vec<(Range<usize>, Operators)>,
+}
+
+impl Worksheet {
+    /// Every problem's numbers read along the lines, top
+    /// to bottom
+    pub fn rows(&self) -> Vec<Problem> {
+        self.problems
+            .iter()
+            .map(|(columns, operator)| Problem {
+                numbers: self
+                    .lines
+                    .iter()
+                    .map(|line| {
+                        number(&line[columns.clone()])
+                    })
+                    .collect(),
+                operator: *operator,
+            })
+            .collect()
+    }
+
+    /// Every problem's numbers read down the columns,
+    /// each column's digits top to bottom, the rightmost
+    /// column first
+    pub fn columns(&self) -> Vec<Problem> {
+        self.problems
+            .iter()
+            .map(|(columns, operator)| Problem {
+                numbers: columns
+                    .clone()
+                    .rev()
+                    .map(|column| {
+                        number(
+                            &self
+                                .lines
+                                .iter()
+                                .map(|line| line[column])
+                                .collect::<Vec<u8>>(),
+                        )
+                    })
+                    .collect(),
+                operator: *operator,
+            })
+            .collect()
+    }
+}
+
+/// The digits in `bytes`, spaces skipped
+fn number(bytes: &[u8]) -> i64 {
+    bytes
+        .iter()
+        .filter(|byte| byte.is_ascii_digit())
+        .fold(0, |number, byte| {
+            number * 10 + i64::from(byte - b'0')
+        })
+}
+
+/// The numbers are aligned in columns, the last line
+/// holds each problem's operator. Checks that both
+/// readings of every problem are numbers.
+pub fn parse(input: &str) -> miette::Result<Worksheet> {
+    let mut lines: Vec<&str> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let Some(operator_line) = lines.pop() else {
+        return Err(Diagnostic::new(
+            input,
+            0..input.len(),
+            "a line of operators",
+        )
+        .into());
+    };
+    for line in &lines {
+        if let Some(column) =
+            line.bytes().position(|byte| {
+                byte != b' ' && !byte.is_ascii_digit()
+            })
+        {
+            let start = input.offset(line) + column;
+            return Err(Diagnostic::new(
+                input,
+                start..start + 1,
+                "a digit or a space",
+            )
+            .into());
+        }
+    }
+
+    let width = lines
+        .iter()
+        .chain([&operator_line])
+        .map(|line| line.len())
+        .max()
+        .unwrap_or(0);
+    let pad = |line: &str| {
+        let mut bytes = line.as_bytes().to_vec();
+        bytes.resize(width, b' ');
+        bytes
+    };
+    let numbers: Vec<Vec<u8>> =
+        lines.iter().map(|line| pad(line)).collect();
+    let operators = pad(operator_line);
+
+    // the runs of columns with something in them
+    let mut problems: Vec<Range<usize>> = Vec::new();
+    let mut start = None;
+    for column in 0..=width {
+        let blank = column == width
+            || operators[column] == b' '
+                && numbers
+                    .iter()
+                    .all(|line| line[column] == b' ');
+        match (blank, start) {
+            (false, None) => start = Some(column),
+            (true, Some(first)) => {
+                problems.push(first..column);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    let operator_columns: Vec<usize> = (0..width)
+        .filter(|&column| operators[column] != b' ')
+        .collect();
+    if operator_columns.len() != problems.len() {
+        return Err(Error::OperatorCount {
+            columns: problems.len(),
+            operators: operator_columns.len(),
+        }
+        .into());
+    }
+    let operators_start = input.offset(operator_line);
+    let span = |line_start: usize,
+                line: &str,
+                columns: &Range<usize>| {
+        line_start + columns.start.min(line.len())
+            ..line_start + columns.end.min(line.len())
+    };
+
+    let mut worksheet = Worksheet {
+        lines: numbers,
+        problems: Vec::with_capacity(problems.len()),
+    };
+    for columns in problems {
+        let under: Vec<usize> = operator_columns
+            .iter()
+            .copied()
+            .filter(|column| columns.contains(column))
+            .collect();
+        let &[column] = under.as_slice() else {
+            return Err(Diagnostic::new(
+                input,
+                span(
+                    operators_start,
+                    operator_line,
+                    &columns,
+                ),
+                "one operator under each problem",
+            )
+            .into());
+        };
+        let (_, operator) =
+            Operators::parse(&operator_line[column..])
+                .map_err(|error| match error {
+                    nom::Err::Error(error)
+                    | nom::Err::Failure(error) => {
+                        error.diagnostic(input)
+                    }
+                    nom::Err::Incomplete(_) => {
+                        unreachable!("complete parsers")
+                    }
+                })?;
+
+        // along the lines every number is a single run
+        // of digits
+        for (line, bytes) in
+            lines.iter().zip(&worksheet.lines)
+        {
+            let digits = bytes[columns.clone()]
+                .trim_ascii()
+                .to_vec();
+            if digits.is_empty()
+                || digits.len() > MAX_DIGITS
+                || digits.contains(&b' ')
+            {
+                return Err(Diagnostic::new(
+                    input,
+                    span(input.offset(line), line, &columns),
+                    format!(
+                        "a number of at most {MAX_DIGITS} digits"
+                    ),
+                )
+                .into());
+            }
+        }
+        // down the columns every column has a digit
+        for column in columns.clone() {
+            let digits = worksheet
+                .lines
+                .iter()
+                .filter(|line| {
+                    line[column].is_ascii_digit()
+                })
+                .count();
+            if digits == 0 || digits > MAX_DIGITS {
+                let start = operators_start + column;
+                return Err(Diagnostic::new(
+                    input,
+                    start..start,
+                    format!(
+                        "between 1 and {MAX_DIGITS} digits above"
+                    ),
+                )
+                .into());
+            }
+        }
+        worksheet.problems.push((columns, operator));
+    }
+    Ok(worksheet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+    fn numbers(problems: &[Problem]) -> Vec<Vec<i64>> {
+        problems
+            .iter()
+            .map(|problem| problem.numbers.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_readings() -> miette::Result<()> {
+        let worksheet = parse(EXAMPLE)?;
+        assert_eq!(
+            vec![
+                vec![123, 45, 6],
+                vec![328, 64, 98],
+                vec![51, 387, 215],
+                vec![64, 23, 314]
+            ],
+            numbers(&worksheet.rows())
+        );
+        assert_eq!(
+            vec![
+                vec![356, 24, 1],
+                vec![8, 248, 369],
+                vec![175, 581, 32],
+                vec![4, 431, 623]
+            ],
+            numbers(&worksheet.columns())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_number() {
+        let error = parse("12 3\n1 23\n*").unwrap_err();
+        assert_eq!(
+            "expected a number of at most 18 digits at line 1, column 1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_bad_operator() {
+        let error = parse("12 3\n4  5\n%  +").unwrap_err();
+        assert_eq!(
+            "expected an operator, one of `+ - * / < > |` at line 3, column 1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_operator_count() {
+        let error = parse("1 2 3\n4 5 6\n* +").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::OperatorCount {
+                columns: 3,
+                operators: 2
+            })
+        ));
+    }
+}
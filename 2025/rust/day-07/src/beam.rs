@@ -0,0 +1,203 @@
+use crate::{error::Error, parse::Manifold};
+
+const SPLITTER: u8 = b'^';
+
+/// A splitter and how many timelines reached it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Splitter {
+    /// the line of the input, the entry points are on
+    /// line 0
+    pub row: usize,
+    pub column: usize,
+    /// 0 if no beam ever gets there
+    pub hits: u128,
+}
+
+/// Where the beams went through a manifold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation {
+    /// every splitter in reading order, reached or not
+    pub splitters: Vec<Splitter>,
+    /// the beams leaving the bottom, counting each path
+    /// a beam could have taken
+    pub timelines: u128,
+}
+
+impl Simulation {
+    /// How many splitters a beam reached
+    pub fn splits_hit(&self) -> usize {
+        self.splitters
+            .iter()
+            .filter(|splitter| splitter.hits > 0)
+            .count()
+    }
+}
+
+/// Sends a beam down from every entry point. Beams in the
+/// same column merge but keep count of their timelines,
+/// a splitter sends each timeline both left and right,
+/// and beams split off the side are lost. Every row of
+/// splitters can double the timelines, so they're
+/// checked against overflowing.
+pub fn simulate(
+    manifold: &Manifold,
+) -> Result<Simulation, Error> {
+    let width = manifold.width;
+    let mut beams = vec![0u128; width];
+    for &start in &manifold.starts {
+        beams[start] += 1;
+    }
+    let mut next = vec![0u128; width];
+    let mut splitters = Vec::new();
+
+    for (row, cells) in manifold.rows.iter().enumerate() {
+        // 1-based, below the entry points' line
+        let line = row + 2;
+        next.fill(0);
+        let mut add = |column: usize, timelines: u128| {
+            next[column] = next[column]
+                .checked_add(timelines)
+                .ok_or(Error::Overflow { line })?;
+            Ok(())
+        };
+        for (column, &cell) in
+            cells.as_bytes().iter().enumerate()
+        {
+            let timelines = beams[column];
+            if cell != SPLITTER {
+                add(column, timelines)?;
+                continue;
+            }
+            splitters.push(Splitter {
+                row: row + 1,
+                column,
+                hits: timelines,
+            });
+            if column > 0 {
+                add(column - 1, timelines)?;
+            }
+            if column + 1 < width {
+                add(column + 1, timelines)?;
+            }
+        }
+        std::mem::swap(&mut beams, &mut next);
+    }
+
+    let timelines = beams
+        .iter()
+        .try_fold(0u128, |sum, &timelines| {
+            sum.checked_add(timelines)
+        })
+        .ok_or(Error::Overflow {
+            line: manifold.rows.len() + 1,
+        })?;
+    Ok(Simulation {
+        splitters,
+        timelines,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse;
+
+    #[test]
+    fn test_splitter_hits() -> miette::Result<()> {
+        let manifold = parse(
+            "..S..
+.....
+..^..
+.....
+.^.^.
+..^..",
+        )?;
+        let simulation = simulate(&manifold)?;
+        assert_eq!(
+            vec![
+                (2, 2, 1),
+                (4, 1, 1),
+                (4, 3, 1),
+                (5, 2, 2)
+            ],
+            simulation
+                .splitters
+                .iter()
+                .map(|s| (s.row, s.column, s.hits))
+                .collect::<Vec<_>>()
+        );
+        // both halves of the first split meet again at
+        // the last splitter
+        assert_eq!(4, simulation.splits_hit());
+        assert_eq!(6, simulation.timelines);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unreached_and_edges() -> miette::Result<()> {
+        let manifold = parse(
+            "S...
+^...
+...^",
+        )?;
+        let simulation = simulate(&manifold)?;
+        // the left half of the split falls off the side
+        assert_eq!(1, simulation.timelines);
+        assert_eq!(1, simulation.splits_hit());
+        assert_eq!(0, simulation.splitters[1].hits);
+        Ok(())
+    }
+
+    #[test]
+    fn test_several_entry_points() -> miette::Result<()> {
+        let manifold = parse(
+            "S.S
+...
+.^.",
+        )?;
+        let simulation = simulate(&manifold)?;
+        assert_eq!(2, simulation.timelines);
+        assert_eq!(0, simulation.splits_hit());
+        Ok(())
+    }
+
+    /// A manifold with an entry point above `rows` rows
+    /// of splitters, a whole row doubles every timeline
+    /// and none get near the sides
+    fn splitter_rows(rows: usize) -> String {
+        let width = 2 * rows + 3;
+        let mid = width / 2;
+        let mut input = format!(
+            "{}S{}\n",
+            ".".repeat(mid),
+            ".".repeat(mid)
+        );
+        for _ in 0..rows {
+            input.push_str(&"^".repeat(width));
+            input.push('\n');
+            input.push_str(&".".repeat(width));
+            input.push('\n');
+        }
+        input
+    }
+
+    #[test]
+    fn test_timelines_past_u64() -> miette::Result<()> {
+        let simulation =
+            simulate(&parse(&splitter_rows(70))?)?;
+        assert!(
+            simulation.timelines > u128::from(u64::MAX)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_timelines_overflow() -> miette::Result<()> {
+        let manifold = parse(&splitter_rows(140))?;
+        assert!(matches!(
+            simulate(&manifold),
+            Err(Error::Overflow { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "the timelines overflowed a u128 by line {line}"
+    )]
+    #[diagnostic(
+        code(day_07::overflow),
+        help("the answer has to fit in 128 bits")
+    )]
+    Overflow { line: usize },
+}
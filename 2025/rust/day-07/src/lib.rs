@@ -1,2 +1,37 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod beam;
+pub mod error;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Parsed = parse::Manifold;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        manifold: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(manifold)
+    }
+
+    fn part2(
+        manifold: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(manifold)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+    ],
+};
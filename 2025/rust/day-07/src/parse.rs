@@ -0,0 +1,78 @@
+use aoc_parse::Diagnostic;
+use nom::Offset;
+
+const ENTRY_POINT: char = 'S';
+
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    /// byte columns of the `S`s in the first line
+    pub starts: Vec<usize>,
+    pub width: usize,
+    /// every line below the entry point
+    pub rows: Vec<String>,
+}
+
+pub fn parse(input: &str) -> miette::Result<Manifold> {
+    let lines: Vec<&str> = input.lines().collect();
+    let first = lines.first().copied().unwrap_or("");
+
+    // byte columns, like the width and the beams
+    let starts: Vec<usize> = first
+        .match_indices(ENTRY_POINT)
+        .map(|(column, _)| column)
+        .collect();
+    if starts.is_empty() {
+        return Err(Diagnostic::new(
+            input,
+            0..first.len(),
+            "an entry point `S` on the first line",
+        )
+        .into());
+    }
+
+    // beams index straight into the rows
+    if let Some(row) =
+        lines.iter().find(|row| row.len() != first.len())
+    {
+        let start = input.offset(row);
+        return Err(Diagnostic::new(
+            input,
+            start..start + row.len(),
+            "a row as wide as the first",
+        )
+        .into());
+    }
+
+    Ok(Manifold {
+        starts,
+        width: first.len(),
+        rows: lines
+            .iter()
+            .skip(1)
+            .map(|row| row.to_string())
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ragged_row() {
+        let error =
+            parse("..S..\n.....\n..^\n").unwrap_err();
+        assert_eq!(
+            "expected a row as wide as the first at line 3, column 1",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_byte_columns() -> miette::Result<()> {
+        // `é` is two bytes wide, like the rows have to be
+        let manifold = parse("é.S\n..^.\n")?;
+        assert_eq!(vec![3], manifold.starts);
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "the boxes ended up in {circuits} circuits, \
+         there's no three largest"
+    )]
+    #[diagnostic(
+        code(day_08::too_few_circuits),
+        help(
+            "fewer connections or more junction boxes \
+             leave more circuits"
+        )
+    )]
+    TooFewCircuits { circuits: usize },
+
+    #[error(
+        "{count} junction boxes never need a connection \
+         to form one circuit"
+    )]
+    #[diagnostic(
+        code(day_08::too_few_boxes),
+        help("the input should list at least two boxes")
+    )]
+    TooFewBoxes { count: usize },
+}
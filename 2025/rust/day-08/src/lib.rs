@@ -1,2 +1,36 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod error;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    type Parsed = Vec<parse::JunctionBox>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        boxes: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(boxes, part1::CONNECT_X_CIRCUITS)
+    }
+
+    fn part2(
+        boxes: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(boxes)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+    ],
+};
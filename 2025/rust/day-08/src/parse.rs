@@ -0,0 +1,59 @@
+use aoc_kd_tree::MAX_COORDINATE;
+use aoc_parse::{IResult, coord3, finish, lines};
+use glam::IVec3;
+use nom::{
+    Parser, character::complete, combinator::verify,
+    error::context,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JunctionBox {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl JunctionBox {
+    pub fn position(self) -> IVec3 {
+        IVec3::new(self.x, self.y, self.z)
+    }
+}
+
+/// A coordinate the k-d tree measures exactly
+fn coordinate(input: &str) -> IResult<'_, i32> {
+    context(
+        "a coordinate within ±2^29",
+        verify(complete::i32, |coordinate: &i32| {
+            coordinate.abs() <= MAX_COORDINATE
+        }),
+    )
+    .parse(input)
+}
+
+pub fn parse(
+    input: &str,
+) -> miette::Result<Vec<JunctionBox>> {
+    finish(
+        input,
+        lines(
+            coord3(coordinate)
+                .map(|(x, y, z)| JunctionBox { x, y, z }),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_out_of_range() {
+        let error =
+            parse("1,2,3\n4,536870913,6").unwrap_err();
+        assert_eq!(
+            "expected a coordinate within ±2^29 at line 2, column 3",
+            error.to_string()
+        );
+        assert!(parse("-536870912,536870912,0").is_ok());
+    }
+}
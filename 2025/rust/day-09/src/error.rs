@@ -0,0 +1,15 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error(
+        "a rectangle needs two red tiles as opposite \
+         corners, found {count}"
+    )]
+    #[diagnostic(
+        code(day_09::too_few_tiles),
+        help("the input should list every red tile")
+    )]
+    TooFewTiles { count: usize },
+}
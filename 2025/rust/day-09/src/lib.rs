@@ -1,2 +1,36 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod error;
+pub mod parse;
 pub mod part1;
 pub mod part2;
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    type Parsed = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(
+        red_tiles: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part1::solve(red_tiles)
+    }
+
+    fn part2(
+        red_tiles: &Self::Parsed,
+    ) -> miette::Result<String> {
+        part2::solve(red_tiles)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+    ],
+};
@@ -0,0 +1,10 @@
+use aoc_parse::{coord2, finish, lines};
+use nom::character::complete;
+
+/// `x,y` positions of the red tiles, in order
+/// around the loop
+pub fn parse(
+    input: &str,
+) -> miette::Result<Vec<(usize, usize)>> {
+    finish(input, lines(coord2(complete::usize)))
+}
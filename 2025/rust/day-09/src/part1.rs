@@ -1,30 +1,35 @@
+use crate::{error::Error, parse::parse};
 use itertools::Itertools;
 
 #[tracing::instrument]
 pub fn process(input: &str) -> miette::Result<String> {
-    let output: i64 = input
-        .lines()
-        .filter_map(|line| {
-            line.split_once(',').map(|(x, y)| {
-                (
-                    x.parse::<i64>().unwrap(),
-                    y.parse::<i64>().unwrap(),
-                )
-            })
-        })
+    solve(&parse(input)?)
+}
+
+pub fn solve(
+    red_tiles: &[(usize, usize)],
+) -> miette::Result<String> {
+    let output = red_tiles
+        .iter()
         .tuple_combinations()
-        .map(|(a, b)| axis_aligned_area(a, b))
+        .map(|(a, b)| axis_aligned_area(*a, *b))
         .max()
-        .unwrap();
+        .ok_or(Error::TooFewTiles {
+            count: red_tiles.len(),
+        })?;
 
     Ok(output.to_string())
 }
 
-fn axis_aligned_area(a: (i64, i64), b: (i64, i64)) -> i64 {
-    let dx = (a.0 - b.0).abs() + 1;
-    let dy = (a.1 - b.1).abs() + 1;
-    let oppervlak = dx * dy;
-    oppervlak
+/// The tiles in the rectangle with opposite corners
+/// `a` and `b`
+pub(crate) fn axis_aligned_area(
+    a: (usize, usize),
+    b: (usize, usize),
+) -> usize {
+    let dx = a.0.abs_diff(b.0) + 1;
+    let dy = a.1.abs_diff(b.1) + 1;
+    dx * dy
 }
 
 #[cfg(test)]
@@ -44,4 +49,13 @@ mod tests {
         assert_eq!("50", process(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_too_few_tiles() {
+        let error = process("7,1").unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<Error>(),
+            Some(Error::TooFewTiles { count: 1 })
+        ));
+    }
 }
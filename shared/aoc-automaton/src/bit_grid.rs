@@ -0,0 +1,130 @@
+use crate::Cells;
+use aoc_grid::Grid;
+use glam::IVec2;
+
+/// A fixed `width` by `height` area, one bit per cell.
+/// Everything outside it is dead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// row-major, `u64::BITS` cells per word
+    words: Vec<u64>,
+    population: usize,
+}
+
+impl BitGrid {
+    /// An area with every cell dead
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            words: vec![
+                0;
+                (width * height)
+                    .div_ceil(u64::BITS as usize)
+            ],
+            population: 0,
+        }
+    }
+
+    /// An area with the cells at `live` alive
+    pub fn from_live(
+        width: usize,
+        height: usize,
+        live: impl IntoIterator<Item = IVec2>,
+    ) -> Self {
+        let mut grid = Self::new(width, height);
+        for position in live {
+            grid.set(position, true);
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn bit(&self, position: IVec2) -> Option<(usize, u64)> {
+        self.in_bounds(position).then(|| {
+            let index = position.y as usize * self.width
+                + position.x as usize;
+            (
+                index / u64::BITS as usize,
+                1 << (index % u64::BITS as usize),
+            )
+        })
+    }
+}
+
+/// Live where the grid is `true`
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        Self::from_live(
+            grid.width(),
+            grid.height(),
+            grid.iter()
+                .filter(|&(_, &alive)| alive)
+                .map(|(position, _)| position),
+        )
+    }
+}
+
+impl Cells for BitGrid {
+    fn is_alive(&self, position: IVec2) -> bool {
+        self.bit(position).is_some_and(|(word, bit)| {
+            self.words[word] & bit != 0
+        })
+    }
+
+    /// Cells outside the area stay dead
+    fn set(&mut self, position: IVec2, alive: bool) {
+        let Some((word, bit)) = self.bit(position) else {
+            return;
+        };
+        let was_alive = self.words[word] & bit != 0;
+        if alive && !was_alive {
+            self.words[word] |= bit;
+            self.population += 1;
+        } else if !alive && was_alive {
+            self.words[word] &= !bit;
+            self.population -= 1;
+        }
+    }
+
+    fn live(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.words.iter().enumerate().flat_map(
+            move |(word, &bits)| {
+                // peels off the lowest set bit each time
+                std::iter::successors(
+                    (bits != 0).then_some(bits),
+                    |&bits| {
+                        let rest = bits & (bits - 1);
+                        (rest != 0).then_some(rest)
+                    },
+                )
+                .map(move |bits| {
+                    let index = word * u64::BITS as usize
+                        + bits.trailing_zeros() as usize;
+                    IVec2::new(
+                        (index % self.width) as i32,
+                        (index / self.width) as i32,
+                    )
+                })
+            },
+        )
+    }
+
+    fn population(&self) -> usize {
+        self.population
+    }
+
+    fn in_bounds(&self, position: IVec2) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+}
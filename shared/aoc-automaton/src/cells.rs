@@ -0,0 +1,46 @@
+use glam::IVec2;
+use std::{collections::HashSet, hash::BuildHasher};
+
+/// Where an [`Automaton`](crate::Automaton) keeps its
+/// live cells
+pub trait Cells {
+    fn is_alive(&self, position: IVec2) -> bool;
+
+    fn set(&mut self, position: IVec2, alive: bool);
+
+    /// Every live cell, in no particular order
+    fn live(&self) -> impl Iterator<Item = IVec2> + '_;
+
+    /// The number of live cells
+    fn population(&self) -> usize;
+
+    /// Whether `position` can ever be alive, cells
+    /// outside stay dead
+    fn in_bounds(&self, _position: IVec2) -> bool {
+        true
+    }
+}
+
+/// Unbounded, memory follows the population rather
+/// than the area
+impl<S: BuildHasher> Cells for HashSet<IVec2, S> {
+    fn is_alive(&self, position: IVec2) -> bool {
+        self.contains(&position)
+    }
+
+    fn set(&mut self, position: IVec2, alive: bool) {
+        if alive {
+            self.insert(position);
+        } else {
+            self.remove(&position);
+        }
+    }
+
+    fn live(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.iter().copied()
+    }
+
+    fn population(&self) -> usize {
+        self.len()
+    }
+}
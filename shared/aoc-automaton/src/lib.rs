@@ -0,0 +1,326 @@
+//! Two state cellular automata with life-like rules:
+//! a cell is born or survives depending on how many of
+//! its neighbours are alive. The live cells sit in any
+//! [`Cells`] backend, a `HashSet` for sparse and
+//! unbounded patterns or a [`BitGrid`] for dense ones.
+
+mod bit_grid;
+mod cells;
+
+pub use bit_grid::BitGrid;
+pub use cells::Cells;
+
+use aoc_grid::NEIGHBORS;
+use glam::IVec2;
+use std::{collections::HashSet, ops::RangeInclusive};
+
+/// Which neighbours count and the live neighbour
+/// counts that give birth to or keep a cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    neighborhood: Vec<IVec2>,
+    /// bit `n` is set if `n` live neighbours do it
+    birth: u64,
+    survive: u64,
+}
+
+impl Rule {
+    /// Nothing is born and nothing survives, add counts
+    /// with [`Rule::birth`] and [`Rule::survive`]
+    pub fn new(neighborhood: &[IVec2]) -> Self {
+        assert!(
+            neighborhood.len() < 64,
+            "at most 63 neighbours"
+        );
+        Self {
+            neighborhood: neighborhood.to_vec(),
+            birth: 0,
+            survive: 0,
+        }
+    }
+
+    /// Conway's game of life, B3/S23
+    pub fn life() -> Self {
+        Self::new(&NEIGHBORS).birth(3..=3).survive(2..=3)
+    }
+
+    /// Dead cells with `counts` live neighbours are born
+    pub fn birth(
+        mut self,
+        counts: RangeInclusive<usize>,
+    ) -> Self {
+        self.birth |= self.mask(counts);
+        self
+    }
+
+    /// Live cells with `counts` live neighbours survive
+    pub fn survive(
+        mut self,
+        counts: RangeInclusive<usize>,
+    ) -> Self {
+        self.survive |= self.mask(counts);
+        self
+    }
+
+    fn mask(&self, counts: RangeInclusive<usize>) -> u64 {
+        counts
+            .filter(|&count| {
+                count <= self.neighborhood.len()
+            })
+            .fold(0, |mask, count| mask | 1 << count)
+    }
+
+    pub fn neighborhood(&self) -> &[IVec2] {
+        &self.neighborhood
+    }
+
+    /// Whether a cell with `live` live neighbours is
+    /// alive in the next generation
+    pub fn next(&self, alive: bool, live: usize) -> bool {
+        let mask =
+            if alive { self.survive } else { self.birth };
+        mask & 1 << live != 0
+    }
+}
+
+/// How many cells changed in one generation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Step {
+    pub born: usize,
+    pub died: usize,
+}
+
+impl Step {
+    /// Nothing changed, the automaton is at a fixed
+    /// point
+    pub fn is_stable(&self) -> bool {
+        self.born == 0 && self.died == 0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Automaton<C> {
+    rule: Rule,
+    cells: C,
+    generation: usize,
+}
+
+impl<C: Cells> Automaton<C> {
+    pub fn new(rule: Rule, cells: C) -> Self {
+        Self {
+            rule,
+            cells,
+            generation: 0,
+        }
+    }
+
+    pub fn cells(&self) -> &C {
+        &self.cells
+    }
+
+    pub fn into_cells(self) -> C {
+        self.cells
+    }
+
+    /// The number of steps taken
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn live_neighbors(&self, position: IVec2) -> usize {
+        self.rule
+            .neighborhood
+            .iter()
+            .filter(|&&offset| {
+                self.cells.is_alive(position + offset)
+            })
+            .count()
+    }
+
+    /// Advances one generation, every cell looks at the
+    /// previous generation
+    pub fn step(&mut self) -> Step {
+        let died: Vec<IVec2> = self
+            .cells
+            .live()
+            .filter(|&position| {
+                !self.rule.next(
+                    true,
+                    self.live_neighbors(position),
+                )
+            })
+            .collect();
+        // only dead cells next to a live one can have
+        // live neighbours
+        let born: Vec<IVec2> = if self.rule.birth == 0 {
+            Vec::new()
+        } else {
+            let mut seen = HashSet::new();
+            self.cells
+                .live()
+                .flat_map(|position| {
+                    self.rule.neighborhood.iter().map(
+                        move |offset| position - offset,
+                    )
+                })
+                .filter(|&position| {
+                    self.cells.in_bounds(position)
+                        && !self.cells.is_alive(position)
+                        && seen.insert(position)
+                        && self.rule.next(
+                            false,
+                            self.live_neighbors(position),
+                        )
+                })
+                .collect()
+        };
+        for &position in &died {
+            self.cells.set(position, false);
+        }
+        for &position in &born {
+            self.cells.set(position, true);
+        }
+        self.generation += 1;
+        Step {
+            born: born.len(),
+            died: died.len(),
+        }
+    }
+
+    /// Every generation's changes, lazily and without
+    /// end, even once nothing changes
+    pub fn steps(
+        &mut self,
+    ) -> impl Iterator<Item = Step> + '_ {
+        std::iter::repeat_with(|| self.step())
+    }
+
+    /// Steps until nothing changes, returning each
+    /// generation's changes without the final stable
+    /// one. Never returns for patterns that oscillate
+    /// or grow forever.
+    pub fn run(&mut self) -> Vec<Step> {
+        self.steps()
+            .take_while(|step| !step.is_stable())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn cells<C: Cells + Default>(live: &[(i32, i32)]) -> C {
+        let mut cells = C::default();
+        for &(x, y) in live {
+            cells.set(IVec2::new(x, y), true);
+        }
+        cells
+    }
+
+    fn live<C: Cells>(cells: &C) -> Vec<(i32, i32)> {
+        let mut live: Vec<(i32, i32)> = cells
+            .live()
+            .map(|position| (position.x, position.y))
+            .collect();
+        live.sort_unstable();
+        live
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule = Rule::life();
+        assert!(rule.next(false, 3));
+        assert!(!rule.next(false, 2));
+        assert!(rule.next(true, 2));
+        assert!(!rule.next(true, 4));
+        assert!(!rule.next(true, 9));
+    }
+
+    #[test]
+    fn test_blinker() {
+        let mut automaton = Automaton::new(
+            Rule::life(),
+            cells::<HashSet<IVec2>>(&[
+                (1, 0),
+                (1, 1),
+                (1, 2),
+            ]),
+        );
+        assert_eq!(
+            Step { born: 2, died: 2 },
+            automaton.step()
+        );
+        assert_eq!(
+            vec![(0, 1), (1, 1), (2, 1)],
+            live(automaton.cells())
+        );
+        automaton.step();
+        assert_eq!(
+            vec![(1, 0), (1, 1), (1, 2)],
+            live(automaton.cells())
+        );
+        assert_eq!(2, automaton.generation());
+    }
+
+    #[test]
+    fn test_glider_is_unbounded_when_sparse() {
+        let glider =
+            [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut automaton = Automaton::new(
+            Rule::life(),
+            cells::<HashSet<IVec2>>(&glider),
+        );
+        // a glider moves one cell diagonally every 4
+        // generations
+        automaton.steps().take(40).for_each(drop);
+        let moved = cells::<HashSet<IVec2>>(
+            &glider.map(|(x, y)| (x + 10, y + 10)),
+        );
+        assert_eq!(live(&moved), live(automaton.cells()));
+    }
+
+    #[test]
+    fn test_bit_grid_clips_at_the_edge() {
+        let glider =
+            [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let mut automaton = Automaton::new(
+            Rule::life(),
+            BitGrid::from_live(
+                6,
+                6,
+                glider.map(|(x, y)| IVec2::new(x, y)),
+            ),
+        );
+        let history = automaton.run();
+        // it turns into a block in the corner
+        assert_eq!(
+            vec![(4, 4), (4, 5), (5, 4), (5, 5)],
+            live(automaton.cells())
+        );
+        assert_eq!(
+            automaton.generation(),
+            history.len() + 1
+        );
+    }
+
+    #[rstest]
+    #[case::sparse(cells::<HashSet<IVec2>>(&[(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (1, 2)]))]
+    #[case::dense(BitGrid::from_live(3, 3, [(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (1, 2)].map(|(x, y)| IVec2::new(x, y))))]
+    fn test_erosion<C: Cells>(#[case] cells: C) {
+        // cells with fewer than 3 live neighbours die
+        let mut automaton = Automaton::new(
+            Rule::new(&NEIGHBORS).survive(3..=8),
+            cells,
+        );
+        assert_eq!(
+            vec![
+                Step { born: 0, died: 3 },
+                Step { born: 0, died: 3 }
+            ],
+            automaton.run()
+        );
+        assert_eq!(0, automaton.cells().population());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::Measurement;
+use std::fmt;
+
+/// How a benchmark's median changed between two runs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub day: String,
+    pub bench: String,
+    /// median in nanoseconds before
+    pub base: f64,
+    /// median in nanoseconds after
+    pub head: f64,
+}
+
+impl Change {
+    /// `1.2` for 20% slower. A base too fast to measure
+    /// is unchanged at 0 and infinitely slower at
+    /// anything else.
+    pub fn ratio(&self) -> f64 {
+        if self.base > 0.0 {
+            self.head / self.base
+        } else if self.head > 0.0 {
+            f64::INFINITY
+        } else {
+            1.0
+        }
+    }
+}
+
+/// The benchmarks that ran in both runs
+#[derive(Debug)]
+pub struct Comparison {
+    pub changes: Vec<Change>,
+    /// the slowdown in percent that counts as a
+    /// regression
+    pub threshold: f64,
+}
+
+impl Comparison {
+    pub fn new(
+        base: &[&Measurement],
+        head: &[&Measurement],
+        threshold: f64,
+    ) -> Self {
+        let changes = head
+            .iter()
+            .filter_map(|after| {
+                let before =
+                    base.iter().find(|before| {
+                        before.day == after.day
+                            && before.bench == after.bench
+                    })?;
+                Some(Change {
+                    day: after.day.clone(),
+                    bench: after.bench.clone(),
+                    base: before.median,
+                    head: after.median,
+                })
+            })
+            .collect();
+        Self { changes, threshold }
+    }
+
+    pub fn is_regression(&self, change: &Change) -> bool {
+        change.ratio() > 1.0 + self.threshold / 100.0
+    }
+
+    pub fn regressions(
+        &self,
+    ) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| self.is_regression(change))
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for change in self.changes.iter() {
+            writeln!(
+                f,
+                "{} {}: {} -> {} ({:+.1}%){}",
+                change.day,
+                change.bench,
+                format_time(change.base),
+                format_time(change.head),
+                (change.ratio() - 1.0) * 100.0,
+                if self.is_regression(change) {
+                    " regression"
+                } else {
+                    ""
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// nanoseconds in the unit divan would use
+fn format_time(nanos: f64) -> String {
+    let (value, unit) = match nanos {
+        n if n >= 1e9 => (n / 1e9, "s"),
+        n if n >= 1e6 => (n / 1e6, "ms"),
+        n if n >= 1e3 => (n / 1e3, "µs"),
+        n => (n, "ns"),
+    };
+    let value = format!("{value:.3}");
+    // only the fraction's zeros, `0.000` is `0`
+    let value = match value.split_once('.') {
+        Some((whole, fraction)) => {
+            match fraction.trim_end_matches('0') {
+                "" => whole.to_string(),
+                fraction => format!("{whole}.{fraction}"),
+            }
+        }
+        None => value,
+    };
+    format!("{value} {unit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(
+        bench: &str,
+        median: f64,
+    ) -> Measurement {
+        Measurement {
+            day: "day-04".to_string(),
+            bench: bench.to_string(),
+            fastest: median,
+            slowest: median,
+            median,
+            mean: median,
+            samples: 100,
+            iters: 100,
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let base = [
+            measurement("part1", 2_000_000.0),
+            measurement("part2", 47_000_000.0),
+            measurement("removed", 1.0),
+        ];
+        let head = [
+            measurement("part1", 2_100_000.0),
+            measurement("part2", 60_000_000.0),
+            measurement("added", 1.0),
+        ];
+        let comparison = Comparison::new(
+            &base.iter().collect::<Vec<_>>(),
+            &head.iter().collect::<Vec<_>>(),
+            10.0,
+        );
+
+        assert_eq!(2, comparison.changes.len());
+        assert_eq!(
+            vec!["part2"],
+            comparison
+                .regressions()
+                .map(|change| change.bench.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "day-04 part1: 2 ms -> 2.1 ms (+5.0%)
+day-04 part2: 47 ms -> 60 ms (+27.7%) regression
+",
+            comparison.to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!("0 ns", format_time(0.0));
+        assert_eq!("120 ns", format_time(120.0));
+        assert_eq!("1.5 µs", format_time(1_500.0));
+        assert_eq!("2 s", format_time(2e9));
+    }
+
+    #[test]
+    fn test_zero_base() {
+        let change = |base, head| Change {
+            day: "day-04".to_string(),
+            bench: "parse".to_string(),
+            base,
+            head,
+        };
+        assert_eq!(1.0, change(0.0, 0.0).ratio());
+        assert_eq!(f64::INFINITY, change(0.0, 3.0).ratio());
+        let comparison = Comparison {
+            changes: vec![change(0.0, 3.0)],
+            threshold: 10.0,
+        };
+        assert_eq!(
+            "day-04 parse: 0 ns -> 3 ns (+inf%) regression\n",
+            comparison.to_string()
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Reads the tables divan prints, such as
+//!
+//! ```text
+//! day_04_bench         fastest       │ slowest       │ median        │ mean          │ samples │ iters
+//! ├─ parse             319.2 µs      │ 386.2 µs      │ 327 µs        │ 328.2 µs      │ 100     │ 100
+//! ╰─ solve                           │               │               │               │         │
+//!    ╰─ part2          47.41 ms      │ 51.69 ms      │ 47.63 ms      │ 47.82 ms      │ 100     │ 100
+//! ```
+
+/// A single benchmark's row, times in nanoseconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// formatted as `day-01` to match the crate names
+    pub day: String,
+    /// the path of the benchmark, `solve::part1`
+    pub bench: String,
+    pub fastest: f64,
+    pub slowest: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub samples: u64,
+    pub iters: u64,
+}
+
+/// Every benchmark row in divan's output, lines that
+/// aren't part of a table are ignored
+pub fn parse(output: &str) -> Vec<Measurement> {
+    let mut measurements = vec![];
+    let mut day = None;
+    // the names of the groups a row is nested in
+    let mut path: Vec<String> = vec![];
+
+    for line in output.lines() {
+        if line.contains("fastest")
+            && line.contains("median")
+        {
+            day = line.split_whitespace().next().map(
+                |name| {
+                    name.trim_end_matches("_bench")
+                        .replace('_', "-")
+                },
+            );
+            path.clear();
+            continue;
+        }
+        let Some(day) = &day else {
+            continue;
+        };
+        let Some((depth, row)) = strip_tree(line) else {
+            continue;
+        };
+
+        let mut columns = row.split('│').map(str::trim);
+        let Some((name, fastest)) =
+            columns.next().map(|first| {
+                first.split_once(' ').unwrap_or((first, ""))
+            })
+        else {
+            continue;
+        };
+        path.truncate(depth - 1);
+        path.push(name.to_string());
+
+        let rest = columns.collect::<Vec<_>>();
+        let [slowest, median, mean, samples, iters] =
+            rest.as_slice()
+        else {
+            continue;
+        };
+        let (
+            Some(fastest),
+            Some(slowest),
+            Some(median),
+            Some(mean),
+            Ok(samples),
+            Ok(iters),
+        ) = (
+            parse_time(fastest),
+            parse_time(slowest),
+            parse_time(median),
+            parse_time(mean),
+            samples.parse(),
+            iters.parse(),
+        )
+        else {
+            // a group, or a counter's throughput line
+            continue;
+        };
+
+        measurements.push(Measurement {
+            day: day.clone(),
+            bench: path.join("::"),
+            fastest,
+            slowest,
+            median,
+            mean,
+            samples,
+            iters,
+        });
+    }
+    measurements
+}
+
+/// Removes the tree drawn in front of a benchmark's
+/// name, returning how deeply it is nested
+fn strip_tree(line: &str) -> Option<(usize, &str)> {
+    let mut rest = line;
+    let mut depth = 1;
+    while let Some(inner) = rest
+        .strip_prefix("│  ")
+        .or_else(|| rest.strip_prefix("   "))
+    {
+        depth += 1;
+        rest = inner;
+    }
+    rest.strip_prefix("├─ ")
+        .or_else(|| rest.strip_prefix("╰─ "))
+        .map(|row| (depth, row))
+}
+
+/// `88.24 µs` in nanoseconds
+fn parse_time(time: &str) -> Option<f64> {
+    let (value, unit) = time.trim().split_once(' ')?;
+    let scale = match unit {
+        "ps" => 1e-3,
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    Some(value.parse::<f64>().ok()? * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "Timer precision: 20 ns
+day_02_bench         fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ parser            957.6 ns      │ 10.45 µs      │ 999.6 ns      │ 1.233 µs      │ 100     │ 100
+├─ part1_ilog_rayon  780.6 µs      │ 4.668 ms      │ 886.1 µs      │ 980.2 µs      │ 100     │ 100
+╰─ solve                           │               │               │               │         │
+   ├─ part1          42.37 ms      │ 44.59 ms      │ 42.9 ms       │ 42.98 ms      │ 100     │ 100
+   ╰─ part2          1.29 s        │ 1.829 s       │ 1.447 s       │ 1.416 s       │ 100     │ 100
+
+day_07_bench  fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ part1      272 µs        │ 307.4 µs      │ 275.4 µs      │ 278.3 µs      │ 100     │ 100
+";
+
+    #[test]
+    fn test_parse() {
+        let measurements = parse(OUTPUT);
+        assert_eq!(
+            vec![
+                ("day-02", "parser", 999.6),
+                ("day-02", "part1_ilog_rayon", 886_100.0),
+                ("day-02", "solve::part1", 42_900_000.0),
+                (
+                    "day-02",
+                    "solve::part2",
+                    1_447_000_000.0
+                ),
+                ("day-07", "part1", 275_400.0),
+            ],
+            measurements
+                .iter()
+                .map(|m| {
+                    (
+                        m.day.as_str(),
+                        m.bench.as_str(),
+                        m.median,
+                    )
+                })
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(957.6, measurements[0].fastest);
+        assert_eq!(100, measurements[0].iters);
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(Some(1_233.0), parse_time("1.233 µs"));
+        assert_eq!(None, parse_time(""));
+    }
+}
@@ -0,0 +1,315 @@
+use crate::Measurement;
+use miette::{IntoDiagnostic, miette};
+use std::{
+    fmt,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Kept at the workspace root next to
+/// `benchmarks.txt`
+pub const HISTORY_FILE: &str = "benchmarks.csv";
+
+const HEADER: &str = "day,bench,commit,timestamp,fastest_ns,slowest_ns,median_ns,mean_ns,samples,iters";
+
+/// A single `just bench` or `just bench-all`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Run {
+    /// seconds since the unix epoch
+    pub timestamp: u64,
+    /// `git rev-parse --short HEAD`, with `-dirty` when
+    /// there were uncommitted changes
+    pub commit: String,
+}
+
+impl fmt::Display for Run {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}@{}", self.commit, self.timestamp)
+    }
+}
+
+/// Every recorded benchmark, one csv row per
+/// measurement
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    records: Vec<(Run, Measurement)>,
+}
+
+impl History {
+    /// Reads the history at `path`, which doesn't need
+    /// to exist yet
+    pub fn open(
+        path: impl Into<PathBuf>,
+    ) -> miette::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path)
+        {
+            Ok(contents) => contents,
+            Err(e)
+                if e.kind()
+                    == std::io::ErrorKind::NotFound =>
+            {
+                String::new()
+            }
+            Err(e) => {
+                return Err(miette!(
+                    "could not read {}: {e}",
+                    path.display()
+                ));
+            }
+        };
+
+        let records = contents
+            .lines()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_record(line).ok_or_else(|| {
+                    miette!(
+                        code = "aoc::bench::history",
+                        help = format!(
+                            "columns are `{HEADER}`"
+                        ),
+                        "line {} of {} is malformed",
+                        i + 1,
+                        path.display()
+                    )
+                })
+            })
+            .collect::<miette::Result<_>>()?;
+        Ok(Self { path, records })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Every run, oldest first
+    pub fn runs(&self) -> Vec<Run> {
+        let mut runs = self
+            .records
+            .iter()
+            .map(|(run, _)| run.clone())
+            .collect::<Vec<_>>();
+        runs.sort();
+        runs.dedup();
+        runs
+    }
+
+    /// The latest run whose commit starts with
+    /// `commit`
+    pub fn find_run(&self, commit: &str) -> Option<Run> {
+        self.runs()
+            .into_iter()
+            .rev()
+            .find(|run| run.commit.starts_with(commit))
+    }
+
+    /// The latest run before `head` that measured at
+    /// least one of its benchmarks. `just bench` only
+    /// records one day, so the run right before might
+    /// have nothing to compare with.
+    pub fn previous_run(&self, head: &Run) -> Option<Run> {
+        let benches = self.measurements(head);
+        self.runs()
+            .into_iter()
+            .take_while(|run| run != head)
+            .filter(|run| {
+                self.measurements(run).iter().any(
+                    |before| {
+                        benches.iter().any(|after| {
+                            before.day == after.day
+                                && before.bench
+                                    == after.bench
+                        })
+                    },
+                )
+            })
+            .last()
+    }
+
+    pub fn measurements(
+        &self,
+        run: &Run,
+    ) -> Vec<&Measurement> {
+        self.records
+            .iter()
+            .filter(|(recorded, _)| recorded == run)
+            .map(|(_, measurement)| measurement)
+            .collect()
+    }
+
+    /// Appends a run to the file and the in-memory
+    /// records
+    pub fn append(
+        &mut self,
+        run: Run,
+        measurements: Vec<Measurement>,
+    ) -> miette::Result<()> {
+        let needs_header = std::fs::metadata(&self.path)
+            .map_or(true, |metadata| metadata.len() == 0);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .into_diagnostic()?;
+        if needs_header {
+            writeln!(file, "{HEADER}").into_diagnostic()?;
+        }
+        for measurement in measurements {
+            let Measurement {
+                day,
+                bench,
+                fastest,
+                slowest,
+                median,
+                mean,
+                samples,
+                iters,
+            } = &measurement;
+            writeln!(
+                file,
+                "{day},{bench},{},{},{fastest},{slowest},{median},{mean},{samples},{iters}",
+                run.commit, run.timestamp
+            )
+            .into_diagnostic()?;
+            self.records.push((run.clone(), measurement));
+        }
+        Ok(())
+    }
+}
+
+fn parse_record(line: &str) -> Option<(Run, Measurement)> {
+    let fields = line.split(',').collect::<Vec<_>>();
+    let &[
+        day,
+        bench,
+        commit,
+        timestamp,
+        fastest,
+        slowest,
+        median,
+        mean,
+        samples,
+        iters,
+    ] = fields.as_slice()
+    else {
+        return None;
+    };
+    Some((
+        Run {
+            timestamp: timestamp.parse().ok()?,
+            commit: commit.to_string(),
+        },
+        Measurement {
+            day: day.to_string(),
+            bench: bench.to_string(),
+            fastest: fastest.parse().ok()?,
+            slowest: slowest.parse().ok()?,
+            median: median.parse().ok()?,
+            mean: mean.parse().ok()?,
+            samples: samples.parse().ok()?,
+            iters: iters.parse().ok()?,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(
+        bench: &str,
+        median: f64,
+    ) -> Measurement {
+        Measurement {
+            day: "day-07".to_string(),
+            bench: bench.to_string(),
+            fastest: median,
+            slowest: median,
+            median,
+            mean: median,
+            samples: 100,
+            iters: 100,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() -> miette::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(HISTORY_FILE);
+        let first = Run {
+            timestamp: 1,
+            commit: "abc123".to_string(),
+        };
+        let second = Run {
+            timestamp: 2,
+            commit: "def456-dirty".to_string(),
+        };
+
+        let mut history = History::open(&path)?;
+        history.append(
+            second.clone(),
+            vec![measurement("part1", 215_800.0)],
+        )?;
+        history.append(
+            first.clone(),
+            vec![
+                measurement("part1", 275_400.0),
+                measurement("solve::part1", 1.5),
+            ],
+        )?;
+
+        let history = History::open(&path)?;
+        assert_eq!(
+            vec![first.clone(), second.clone()],
+            history.runs()
+        );
+        assert_eq!(Some(second), history.find_run("def"));
+        assert_eq!(
+            vec![
+                &measurement("part1", 275_400.0),
+                &measurement("solve::part1", 1.5)
+            ],
+            history.measurements(&first)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_previous_run() -> miette::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let mut history =
+            History::open(dir.path().join(HISTORY_FILE))?;
+        let run = |timestamp| Run {
+            timestamp,
+            commit: "abc123".to_string(),
+        };
+        let day_04 = Measurement {
+            day: "day-04".to_string(),
+            ..measurement("part2", 1.0)
+        };
+        history.append(
+            run(1),
+            vec![measurement("part1", 1.0)],
+        )?;
+        history.append(run(2), vec![day_04])?;
+        history.append(
+            run(3),
+            vec![measurement("part1", 2.0)],
+        )?;
+
+        // `just bench day-04 part2` ran in between
+        assert_eq!(
+            Some(run(1)),
+            history.previous_run(&run(3))
+        );
+        assert_eq!(None, history.previous_run(&run(2)));
+        Ok(())
+    }
+}
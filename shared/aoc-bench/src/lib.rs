@@ -0,0 +1,7 @@
+pub mod compare;
+pub mod divan;
+pub mod history;
+
+pub use compare::Comparison;
+pub use divan::Measurement;
+pub use history::{History, Run};
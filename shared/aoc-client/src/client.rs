@@ -0,0 +1,291 @@
+use crate::Error;
+use miette::miette;
+use reqwest::{
+    StatusCode, blocking::RequestBuilder, header::COOKIE,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// Where puzzles are fetched from unless
+/// `$AOC_BASE_URL` is set
+pub const DEFAULT_BASE_URL: &str =
+    "https://adventofcode.com";
+
+/// Overrides [`DEFAULT_BASE_URL`], used to point the
+/// client at a stand-in server
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+/// The session cookie, copied from the browser as
+/// described in the justfile
+pub const SESSION_VAR: &str = "SESSION";
+
+/// Directory inputs are cached in, defaults to
+/// `$XDG_CACHE_HOME/aoc` or `~/.cache/aoc`
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE";
+
+const USER_AGENT: &str = concat!(
+    "github.com/feliblo/advent-of-code ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// A single day of a single year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Puzzle {
+    pub fn new(year: u16, day: u8) -> Self {
+        Self { year, day }
+    }
+
+    /// Parses a crate name such as `day-01`, or
+    /// `y2024-day-01` which overrides `year`
+    pub fn from_day_name(
+        year: u16,
+        name: &str,
+    ) -> miette::Result<Self> {
+        let (year, day) = match name
+            .strip_prefix('y')
+            .and_then(|name| name.split_once('-'))
+        {
+            Some((year, day)) => (year.parse().ok(), day),
+            None => (Some(year), name),
+        };
+        let day = day
+            .strip_prefix("day-")
+            .and_then(|day| day.parse().ok())
+            .filter(|day| (1..=25).contains(day));
+        year.zip(day)
+            .map(|(year, day)| Self::new(year, day))
+            .ok_or_else(|| {
+                miette!(
+                    help = "days are formatted as `day-01` \
+                            or `y2025-day-01` to match \
+                            the crate names",
+                    "`{name}` is not a day"
+                )
+            })
+    }
+
+    /// The crate name, `day-01`
+    pub fn day_name(&self) -> String {
+        format!("day-{:02}", self.day)
+    }
+
+    pub(crate) fn url(&self, base_url: &str) -> String {
+        format!(
+            "{base_url}/{}/day/{}",
+            self.year, self.day
+        )
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{} {}", self.year, self.day_name())
+    }
+}
+
+/// Talks to adventofcode.com, or whatever
+/// `base_url` points at.
+///
+/// The session is only needed once something has to
+/// be requested, cached inputs are read without it.
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub(crate) base_url: String,
+    pub(crate) session: Option<String>,
+    pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) http: reqwest::blocking::Client,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: String::new(),
+            session: None,
+            cache_dir: None,
+            http: reqwest::blocking::Client::builder()
+                .user_agent(USER_AGENT)
+                .build()
+                .expect("the tls backend is available"),
+        }
+        .with_base_url(base_url)
+    }
+
+    /// Configured from `$AOC_BASE_URL`, `$SESSION`
+    /// and `$AOC_CACHE`
+    pub fn from_env() -> Self {
+        let base_url = std::env::var(BASE_URL_VAR)
+            .unwrap_or(DEFAULT_BASE_URL.to_string());
+        let client = Self::new(base_url);
+        let client = match std::env::var(SESSION_VAR) {
+            Ok(session) if !session.is_empty() => {
+                client.with_session(session)
+            }
+            _ => client,
+        };
+        match default_cache_dir() {
+            Some(dir) => client.with_cache_dir(dir),
+            None => client,
+        }
+    }
+
+    pub fn with_base_url(
+        self,
+        base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            base_url: base_url
+                .into()
+                .trim_end_matches('/')
+                .to_string(),
+            ..self
+        }
+    }
+
+    pub fn with_session(
+        self,
+        session: impl Into<String>,
+    ) -> Self {
+        Self {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    pub fn with_cache_dir(
+        self,
+        cache_dir: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            cache_dir: Some(cache_dir.into()),
+            ..self
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn cache_dir(&self) -> Option<&Path> {
+        self.cache_dir.as_deref()
+    }
+
+    /// Sends `request` for `puzzle` with the session
+    /// cookie, turning error statuses into [`Error`]s
+    pub(crate) fn send(
+        &self,
+        puzzle: Puzzle,
+        request: RequestBuilder,
+    ) -> Result<String, Error> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or(Error::MissingSession { puzzle })?;
+        let request = request
+            .header(COOKIE, format!("session={session}"))
+            .build()
+            .map_err(|source| Error::Request {
+                url: puzzle.url(&self.base_url),
+                source,
+            })?;
+        let url = request.url().to_string();
+        tracing::info!(%url, method = %request.method());
+
+        let response = self.http.execute(request).map_err(
+            |source| Error::Request {
+                url: url.clone(),
+                source,
+            },
+        )?;
+        match response.status() {
+            StatusCode::NOT_FOUND => {
+                return Err(Error::NotUnlocked { puzzle });
+            }
+            StatusCode::BAD_REQUEST
+            | StatusCode::UNAUTHORIZED
+            | StatusCode::FORBIDDEN => {
+                return Err(Error::ExpiredSession);
+            }
+            status if !status.is_success() => {
+                return Err(Error::Status { status, url });
+            }
+            _ => {}
+        }
+        response.text().map_err(|source| Error::Request {
+            url,
+            source,
+        })
+    }
+}
+
+fn default_cache_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_DIR_VAR) {
+        return Some(dir.into());
+    }
+    if let Some(dir) = std::env::var_os("XDG_CACHE_HOME") {
+        return Some(PathBuf::from(dir).join("aoc"));
+    }
+    std::env::var_os("HOME").map(|home| {
+        PathBuf::from(home).join(".cache").join("aoc")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("day-01", 1)]
+    #[case("day-25", 25)]
+    #[case("y2025-day-03", 3)]
+    fn test_from_day_name(
+        #[case] name: &str,
+        #[case] day: u8,
+    ) -> miette::Result<()> {
+        assert_eq!(
+            Puzzle::new(2025, day),
+            Puzzle::from_day_name(2025, name)?
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("day-00")]
+    #[case("day-26")]
+    #[case("01")]
+    #[case("aoc")]
+    #[case("y20x5-day-01")]
+    fn test_from_day_name_invalid(#[case] name: &str) {
+        assert!(Puzzle::from_day_name(2025, name).is_err());
+    }
+
+    #[test]
+    fn test_from_day_name_year() -> miette::Result<()> {
+        assert_eq!(
+            Puzzle::new(2024, 7),
+            Puzzle::from_day_name(2025, "y2024-day-07")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url() {
+        let client = Client::new("http://localhost/");
+        assert_eq!(
+            "http://localhost/2024/day/7",
+            Puzzle::new(2024, 7).url(client.base_url())
+        );
+    }
+}
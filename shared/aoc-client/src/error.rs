@@ -0,0 +1,106 @@
+use crate::{Puzzle, client::SESSION_VAR, submit::Verdict};
+use aoc_core::Part;
+use miette::Diagnostic;
+use reqwest::StatusCode;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error("no session cookie to fetch {puzzle} with")]
+    #[diagnostic(
+        code(aoc::client::missing_session),
+        help(
+            "set `{SESSION_VAR}` in `.env`, the justfile \
+             explains where to find it"
+        )
+    )]
+    MissingSession { puzzle: Puzzle },
+
+    #[error("the session cookie was rejected")]
+    #[diagnostic(
+        code(aoc::client::expired_session),
+        help(
+            "sessions expire after about a month, log in \
+             again and update `{SESSION_VAR}` in `.env`"
+        )
+    )]
+    ExpiredSession,
+
+    #[error("{puzzle} has not unlocked yet")]
+    #[diagnostic(
+        code(aoc::client::not_unlocked),
+        help("puzzles unlock at midnight EST (UTC-5)")
+    )]
+    NotUnlocked { puzzle: Puzzle },
+
+    #[error("unexpected {status} from {url}")]
+    #[diagnostic(code(aoc::client::status))]
+    Status { status: StatusCode, url: String },
+
+    #[error("request to {url} failed")]
+    #[diagnostic(code(aoc::client::request))]
+    Request {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+
+    #[error("could not cache input at {}", path.display())]
+    #[diagnostic(code(aoc::client::cache))]
+    Cache {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(
+        "could not understand the response to {puzzle} {part}"
+    )]
+    #[diagnostic(
+        code(aoc::client::unknown_response),
+        help("the page said: {text}")
+    )]
+    UnknownResponse {
+        puzzle: Puzzle,
+        part: Part,
+        text: String,
+    },
+
+    #[error(
+        "`{answer}` was already submitted for {puzzle} {part}, it was {verdict}"
+    )]
+    #[diagnostic(
+        code(aoc::client::already_submitted),
+        help(
+            "every submission is recorded in {}, remove \
+             the line to send it anyway",
+            ledger.display()
+        )
+    )]
+    AlreadySubmitted {
+        puzzle: Puzzle,
+        part: Part,
+        answer: String,
+        verdict: Verdict,
+        ledger: PathBuf,
+    },
+
+    #[error("could not use the submission ledger at {}", path.display())]
+    #[diagnostic(code(aoc::client::ledger))]
+    Ledger {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("line {line} of the submission ledger at {} is malformed", path.display())]
+    #[diagnostic(
+        code(aoc::client::ledger),
+        help(
+            "lines are `year, day, part, answer, verdict, \
+             unix timestamp` separated by tabs"
+        )
+    )]
+    MalformedLedger { path: PathBuf, line: usize },
+}
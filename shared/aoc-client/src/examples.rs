@@ -0,0 +1,147 @@
+use crate::{Client, Error, Puzzle, html};
+use aoc_core::{Part, examples::Example};
+
+impl Client {
+    /// The puzzle description. Part 2 is only on the
+    /// page once part 1 is solved, so it isn't cached.
+    pub fn puzzle_page(
+        &self,
+        puzzle: Puzzle,
+    ) -> Result<String, Error> {
+        self.send(
+            puzzle,
+            self.http.get(puzzle.url(&self.base_url)),
+        )
+    }
+}
+
+/// Pulls each part's example out of a puzzle
+/// description.
+///
+/// The first `<pre><code>` block of a part is its
+/// example input, and the last highlighted
+/// `<code><em>` is the answer for it. Part 2 usually
+/// reuses part 1's example, so it falls back to that
+/// input when it has no block of its own.
+pub fn scrape(page: &str) -> Vec<(Part, Example)> {
+    let mut examples = vec![];
+    let mut previous_input = None;
+    for (article, part) in html::articles(page)
+        .into_iter()
+        .zip([Part::One, Part::Two])
+    {
+        let input = html::between(
+            article,
+            "<pre><code>",
+            "</code></pre>",
+        )
+        .first()
+        .map(|(_, block)| html::text(block))
+        .or(previous_input);
+        previous_input = input.clone();
+
+        if let (Some(input), Some(answer)) =
+            (input, answer(article))
+        {
+            examples.push((
+                part,
+                Example {
+                    name: part.to_string(),
+                    input,
+                    answer,
+                },
+            ));
+        }
+    }
+    examples
+}
+
+/// The last `<code><em>` (or `<em><code>`) on a
+/// single line
+fn answer(article: &str) -> Option<String> {
+    let code_em =
+        html::between(article, "<code>", "</code>")
+            .into_iter()
+            .filter(|(_, html)| {
+                html.starts_with("<em>")
+                    && html.ends_with("</em>")
+            });
+    let em_code = html::between(article, "<em>", "</em>")
+        .into_iter()
+        .filter(|(_, html)| {
+            html.starts_with("<code>")
+                && html.ends_with("</code>")
+        });
+    code_em
+        .chain(em_code)
+        .filter(|(_, html)| !html.contains('\n'))
+        .max_by_key(|&(offset, _)| offset)
+        .map(|(_, html)| html::text(html))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +
+";
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 6: Trash Compactor ---</h2>
+<p>Here's an example worksheet:</p>
+<pre><code>123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +
+</code></pre>
+<p>In this worksheet, the problems are <code>123 * 45 * 6 = <em>33210</em></code> and so on.</p>
+<p>Adding them together produces the grand total, <code><em>4277556</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Reading right-to-left one column at a time, the grand total is now <em><code>3263827</code></em>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_scrape() {
+        assert_eq!(
+            vec![
+                (
+                    Part::One,
+                    Example {
+                        name: "part1".to_string(),
+                        input: EXAMPLE.to_string(),
+                        answer: "4277556".to_string(),
+                    }
+                ),
+                (
+                    Part::Two,
+                    Example {
+                        name: "part2".to_string(),
+                        input: EXAMPLE.to_string(),
+                        answer: "3263827".to_string(),
+                    }
+                ),
+            ],
+            scrape(PAGE)
+        );
+    }
+
+    #[test]
+    fn test_scrape_before_part2() {
+        let (part1, _) =
+            PAGE.split_once("<p>Your puzzle").unwrap();
+        let examples = scrape(part1);
+        assert_eq!(1, examples.len());
+        assert_eq!(Part::One, examples[0].0);
+    }
+}
@@ -0,0 +1,88 @@
+//! Just enough HTML handling for the pages
+//! adventofcode.com returns
+
+/// The inner html of every `<article>`, part 1's
+/// description comes first
+pub fn articles(page: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = page;
+    while let Some((_, after)) = rest.split_once("<article")
+    {
+        let Some((_, after)) = after.split_once('>') else {
+            break;
+        };
+        let Some((article, after)) =
+            after.split_once("</article>")
+        else {
+            break;
+        };
+        articles.push(article);
+        rest = after;
+    }
+    articles
+}
+
+/// The inner html of everything between `open` and
+/// `close`, with its offset in `html`
+pub fn between<'a>(
+    html: &'a str,
+    open: &str,
+    close: &str,
+) -> Vec<(usize, &'a str)> {
+    let mut found = vec![];
+    let mut offset = 0;
+    while let Some(start) = html[offset..].find(open) {
+        let start = offset + start + open.len();
+        let Some(end) = html[start..].find(close) else {
+            break;
+        };
+        found.push((start, &html[start..start + end]));
+        offset = start + end + close.len();
+    }
+    found
+}
+
+/// The text content of `html`, tags removed and
+/// entities decoded
+pub fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_articles() {
+        let page = "<main><article class=\"day-desc\"><p>one</p>\
+                    </article><p>between</p>\
+                    <article class=\"day-desc\">two</article>\
+                    </main>";
+        assert_eq!(
+            vec!["<p>one</p>", "two"],
+            articles(page)
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            "a <b> & 'c'",
+            text("<em>a</em> &lt;b&gt; &amp; &#39;c&#39;")
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Client, Error, Puzzle};
+use std::path::PathBuf;
+
+impl Client {
+    /// The puzzle input, read from the cache when it
+    /// was fetched before
+    pub fn input(
+        &self,
+        puzzle: Puzzle,
+    ) -> Result<String, Error> {
+        let cache_path = self.input_cache_path(puzzle);
+        if let Some(path) = &cache_path
+            && let Ok(input) = std::fs::read_to_string(path)
+        {
+            tracing::debug!(?path, "cached input");
+            return Ok(input);
+        }
+
+        let input = self.fetch_input(puzzle)?;
+
+        if let Some(path) = cache_path {
+            let write = |path: &PathBuf| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(path, &input)
+            };
+            write(&path).map_err(|source| {
+                Error::Cache { path, source }
+            })?;
+        }
+        Ok(input)
+    }
+
+    fn input_cache_path(
+        &self,
+        puzzle: Puzzle,
+    ) -> Option<PathBuf> {
+        self.cache_dir.as_ref().map(|dir| {
+            dir.join(puzzle.year.to_string())
+                .join(format!("{}.txt", puzzle.day_name()))
+        })
+    }
+
+    fn fetch_input(
+        &self,
+        puzzle: Puzzle,
+    ) -> Result<String, Error> {
+        let url =
+            format!("{}/input", puzzle.url(&self.base_url));
+        let body = self.send(puzzle, self.http.get(url))?;
+        // an expired session is redirected to the login
+        // page instead of getting an error status
+        if is_html(&body) {
+            return Err(Error::ExpiredSession);
+        }
+        Ok(body)
+    }
+}
+
+pub(crate) fn is_html(body: &str) -> bool {
+    let start = body.trim_start();
+    start.starts_with("<!DOCTYPE")
+        || start.starts_with("<!doctype")
+        || start.starts_with("<html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+
+    const INPUT: &str = "L68\nL30\nR48\n";
+
+    fn client(server: &TestServer) -> Client {
+        Client::new(&server.base_url).with_session("abc")
+    }
+
+    #[test]
+    fn test_input() -> miette::Result<()> {
+        let server = TestServer::new(vec![(200, INPUT)]);
+        let input =
+            client(&server).input(Puzzle::new(2024, 1))?;
+        assert_eq!(INPUT, input);
+
+        let request = server.request();
+        assert!(
+            request.starts_with("GET /2024/day/1/input ")
+        );
+        assert!(
+            request
+                .to_lowercase()
+                .contains("cookie: session=abc")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_expired_session() {
+        let server = TestServer::new(vec![(
+            200,
+            "<!DOCTYPE html>\n<html><body>log in</body></html>",
+        )]);
+        let error = client(&server)
+            .input(Puzzle::new(2025, 1))
+            .unwrap_err();
+        assert!(matches!(error, Error::ExpiredSession));
+    }
+
+    #[test]
+    fn test_not_unlocked() {
+        let server = TestServer::new(vec![(
+            404,
+            "Please don't repeatedly request this \
+             endpoint before it unlocks!",
+        )]);
+        let error = client(&server)
+            .input(Puzzle::new(2025, 12))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::NotUnlocked { puzzle }
+                if puzzle == Puzzle::new(2025, 12)
+        ));
+    }
+
+    #[test]
+    fn test_missing_session() {
+        let error = Client::new("http://127.0.0.1:9")
+            .input(Puzzle::new(2025, 1))
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::MissingSession { .. }
+        ));
+    }
+
+    #[test]
+    fn test_cached_input() -> miette::Result<()> {
+        let cache = tempfile::tempdir().unwrap();
+        let server = TestServer::new(vec![(200, INPUT)]);
+        let client =
+            client(&server).with_cache_dir(cache.path());
+        let puzzle = Puzzle::new(2025, 3);
+
+        assert_eq!(INPUT, client.input(puzzle)?);
+        assert_eq!(
+            INPUT,
+            std::fs::read_to_string(
+                cache.path().join("2025/day-03.txt")
+            )
+            .unwrap()
+        );
+        // the server only answers once, a second
+        // request would fail
+        assert_eq!(INPUT, client.input(puzzle)?);
+        assert_eq!(1, server.requests().len());
+        Ok(())
+    }
+}
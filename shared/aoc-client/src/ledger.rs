@@ -0,0 +1,259 @@
+use crate::{Error, Puzzle, submit::Verdict};
+use aoc_core::Part;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Where the ledger is kept, defaults to
+/// `submissions.tsv` in the cache directory
+pub const LEDGER_VAR: &str = "AOC_LEDGER";
+
+/// A single recorded submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub puzzle: Puzzle,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// seconds since the unix epoch
+    pub submitted_at: u64,
+}
+
+/// Every answer that was checked and the verdict it
+/// got, so the same wrong answer is never sent twice.
+///
+/// Stored as one tab separated line per submission,
+/// `2025 day-01 part1 42 too high 1764547200`, so it
+/// can be read and edited by hand.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// `$AOC_LEDGER`, or `submissions.tsv` in
+    /// `cache_dir`
+    pub fn default_path(
+        cache_dir: Option<&Path>,
+    ) -> Option<PathBuf> {
+        std::env::var_os(LEDGER_VAR)
+            .map(PathBuf::from)
+            .or_else(|| {
+                cache_dir
+                    .map(|dir| dir.join("submissions.tsv"))
+            })
+    }
+
+    /// Reads the ledger at `path`, which doesn't need
+    /// to exist yet
+    pub fn open(
+        path: impl Into<PathBuf>,
+    ) -> Result<Self, Error> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path)
+        {
+            Ok(contents) => contents,
+            Err(e)
+                if e.kind()
+                    == std::io::ErrorKind::NotFound =>
+            {
+                String::new()
+            }
+            Err(source) => {
+                return Err(Error::Ledger { path, source });
+            }
+        };
+
+        let entries = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_entry(line).ok_or_else(|| {
+                    Error::MalformedLedger {
+                        path: path.clone(),
+                        line: i + 1,
+                    }
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { path, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The verdict `answer` is known to get, either
+    /// from submitting it before or because it is
+    /// beyond an answer that was too high or too low
+    pub fn verdict(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &str,
+    ) -> Option<Verdict> {
+        let previous =
+            self.entries.iter().filter(|entry| {
+                entry.puzzle == puzzle && entry.part == part
+            });
+        let number = answer.parse::<i128>().ok();
+
+        let mut known = None;
+        for entry in previous {
+            if entry.answer == answer {
+                return Some(entry.verdict);
+            }
+            let (Some(number), Ok(previous)) =
+                (number, entry.answer.parse::<i128>())
+            else {
+                continue;
+            };
+            match entry.verdict {
+                Verdict::TooHigh if number >= previous => {
+                    known = Some(Verdict::TooHigh);
+                }
+                Verdict::TooLow if number <= previous => {
+                    known = Some(Verdict::TooLow);
+                }
+                _ => {}
+            }
+        }
+        known
+    }
+
+    /// Appends a submission to the file and the
+    /// in-memory entries
+    pub fn record(
+        &mut self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &str,
+        verdict: Verdict,
+    ) -> Result<(), Error> {
+        let entry = Entry {
+            puzzle,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs()),
+        };
+
+        let append = || {
+            if let Some(dir) = self.path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                entry.puzzle.year,
+                entry.puzzle.day_name(),
+                entry.part,
+                entry.answer,
+                entry.verdict,
+                entry.submitted_at
+            )
+        };
+        append().map_err(|source| Error::Ledger {
+            path: self.path.clone(),
+            source,
+        })?;
+
+        self.entries.push(entry);
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.split('\t');
+    let year = fields.next()?.parse().ok()?;
+    let puzzle =
+        Puzzle::from_day_name(year, fields.next()?).ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let answer = fields.next()?.to_string();
+    let verdict = fields.next()?.parse().ok()?;
+    let submitted_at = fields.next()?.parse().ok()?;
+    Some(Entry {
+        puzzle,
+        part,
+        answer,
+        verdict,
+        submitted_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const PUZZLE: Puzzle = Puzzle { year: 2025, day: 1 };
+
+    #[rstest]
+    #[case(Some(Verdict::TooHigh), "100")]
+    #[case(Some(Verdict::TooHigh), "150")]
+    #[case(Some(Verdict::TooLow), "10")]
+    #[case(Some(Verdict::Wrong), "50")]
+    #[case(None, "60")]
+    #[case(None, "abc")]
+    fn test_verdict(
+        #[case] expected: Option<Verdict>,
+        #[case] answer: &str,
+    ) -> miette::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let mut ledger =
+            Ledger::open(dir.path().join("ledger.tsv"))?;
+        for (answer, verdict) in [
+            ("100", Verdict::TooHigh),
+            ("10", Verdict::TooLow),
+            ("50", Verdict::Wrong),
+        ] {
+            ledger.record(
+                PUZZLE,
+                Part::One,
+                answer,
+                verdict,
+            )?;
+        }
+
+        assert_eq!(
+            expected,
+            ledger.verdict(PUZZLE, Part::One, answer)
+        );
+        // other parts are unaffected
+        assert_eq!(
+            None,
+            ledger.verdict(PUZZLE, Part::Two, answer)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_ledger() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ledger.tsv");
+        std::fs::write(
+            &path,
+            "2025\tday-01\tpart1\t42\twrong\t0\n\
+             2025\tday-01\tpart1\n",
+        )
+        .unwrap();
+        assert!(matches!(
+            Ledger::open(&path),
+            Err(Error::MalformedLedger { line: 2, .. })
+        ));
+    }
+}
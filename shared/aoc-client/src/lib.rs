@@ -0,0 +1,14 @@
+pub mod client;
+pub mod error;
+pub mod examples;
+mod html;
+pub mod input;
+pub mod ledger;
+pub mod submit;
+#[cfg(test)]
+mod test_server;
+
+pub use client::{Client, Puzzle};
+pub use error::Error;
+pub use ledger::Ledger;
+pub use submit::Verdict;
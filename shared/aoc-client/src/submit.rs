@@ -0,0 +1,394 @@
+use crate::{Client, Error, Puzzle, html, ledger::Ledger};
+use aoc_core::Part;
+use reqwest::header::CONTENT_TYPE;
+use std::{fmt, str::FromStr, time::Duration};
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without saying in which direction
+    Wrong,
+    /// the part was solved before, so the answer
+    /// wasn't checked
+    AlreadySolved,
+    /// an answer was submitted too recently, so this
+    /// one wasn't checked
+    RateLimited {
+        wait: Option<Duration>,
+    },
+}
+
+impl Verdict {
+    /// whether the answer was actually checked, only
+    /// those are worth recording
+    pub fn is_judged(self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct
+                | Verdict::TooHigh
+                | Verdict::TooLow
+                | Verdict::Wrong
+        )
+    }
+
+    /// Classifies the page returned after posting an
+    /// answer
+    pub fn from_page(page: &str) -> Option<Self> {
+        let text = article_text(page);
+        let verdict = if text
+            .contains("That's the right answer")
+        {
+            Verdict::Correct
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text
+            .contains("That's not the right answer")
+        {
+            Verdict::Wrong
+        } else if text
+            .contains("Did you already complete it")
+        {
+            Verdict::AlreadySolved
+        } else if text
+            .contains("You gave an answer too recently")
+        {
+            Verdict::RateLimited {
+                wait: parse_wait(&text),
+            }
+        } else {
+            return None;
+        };
+        Some(verdict)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::AlreadySolved => {
+                write!(f, "already solved")
+            }
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(
+                    f,
+                    "rate limited, wait {}s",
+                    wait.as_secs()
+                )
+            }
+            Verdict::RateLimited { wait: None } => {
+                write!(f, "rate limited")
+            }
+        }
+    }
+}
+
+/// Only the judged verdicts, as stored in the
+/// [`Ledger`]
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            other => {
+                Err(format!("`{other}` is not a verdict"))
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Posts `answer` for `part` of `puzzle` and
+    /// records the verdict in `ledger`.
+    ///
+    /// Answers the ledger already has a verdict for
+    /// are never sent again.
+    pub fn submit(
+        &self,
+        puzzle: Puzzle,
+        part: Part,
+        answer: &str,
+        ledger: &mut Ledger,
+    ) -> Result<Verdict, Error> {
+        let answer = answer.trim();
+        if let Some(verdict) =
+            ledger.verdict(puzzle, part, answer)
+        {
+            return Err(Error::AlreadySubmitted {
+                puzzle,
+                part,
+                answer: answer.to_string(),
+                verdict,
+                ledger: ledger.path().to_path_buf(),
+            });
+        }
+
+        let url = format!(
+            "{}/answer",
+            puzzle.url(&self.base_url)
+        );
+        let form = format!(
+            "level={}&answer={}",
+            part.number(),
+            form_encode(answer)
+        );
+        let page = self.send(
+            puzzle,
+            self.http
+                .post(url)
+                .header(
+                    CONTENT_TYPE,
+                    "application/x-www-form-urlencoded",
+                )
+                .body(form),
+        )?;
+
+        let verdict = Verdict::from_page(&page)
+            .ok_or_else(|| Error::UnknownResponse {
+                puzzle,
+                part,
+                text: article_text(&page)
+                    .chars()
+                    .take(200)
+                    .collect(),
+            })?;
+        if verdict.is_judged() {
+            ledger.record(puzzle, part, answer, verdict)?;
+        }
+        Ok(verdict)
+    }
+}
+
+/// The text of the page's `<article>`, or the whole
+/// page, on a single line
+fn article_text(page: &str) -> String {
+    let article = html::articles(page)
+        .first()
+        .copied()
+        .unwrap_or(page);
+    html::text(article)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `You have 1m 5s left to wait.`
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for amount in wait.split_whitespace() {
+        let (number, unit) =
+            amount.split_at(amount.len().checked_sub(1)?);
+        let unit = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            _ => return None,
+        };
+        seconds += number.parse::<u64>().ok()? * unit;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~' => (byte as char).to_string(),
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::TestServer;
+    use rstest::rstest;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\
+             <article><p>{article}</p></article>\
+             </main></body></html>"
+        )
+    }
+
+    #[rstest]
+    #[case(
+        Verdict::Correct,
+        "That's the right answer!  You are one gold star closer to decorating the North Pole."
+    )]
+    #[case(
+        Verdict::TooHigh,
+        "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data."
+    )]
+    #[case(
+        Verdict::TooLow,
+        "That's not the right answer; your answer is too low."
+    )]
+    #[case(
+        Verdict::Wrong,
+        "That's not the right answer.  If you're stuck, make sure you're using the full input data."
+    )]
+    #[case(
+        Verdict::AlreadySolved,
+        "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2025/day/1\">[Return to Day 1]</a>"
+    )]
+    #[case(
+        Verdict::RateLimited { wait: Some(Duration::from_secs(65)) },
+        "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2025/day/1\">[Return to Day 1]</a>"
+    )]
+    fn test_verdict_from_page(
+        #[case] expected: Verdict,
+        #[case] article: &str,
+    ) {
+        assert_eq!(
+            Some(expected),
+            Verdict::from_page(&page(article))
+        );
+    }
+
+    #[test]
+    fn test_submit() -> miette::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let mut ledger =
+            Ledger::open(dir.path().join("ledger.tsv"))?;
+        let server = TestServer::new(vec![(
+            200,
+            &page("That's the right answer!"),
+        )]);
+        let client = Client::new(&server.base_url)
+            .with_session("abc");
+
+        let verdict = client.submit(
+            Puzzle::new(2025, 6),
+            Part::Two,
+            "3263827\n",
+            &mut ledger,
+        )?;
+        assert_eq!(Verdict::Correct, verdict);
+
+        let request = server.request();
+        assert!(
+            request.starts_with("POST /2025/day/6/answer ")
+        );
+        assert!(
+            request.ends_with("level=2&answer=3263827")
+        );
+        assert_eq!(1, ledger.entries().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrong_answer_is_not_resubmitted()
+    -> miette::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ledger.tsv");
+        let server = TestServer::new(vec![(
+            200,
+            &page(
+                "That's not the right answer; your answer \
+                 is too high.",
+            ),
+        )]);
+        let client = Client::new(&server.base_url)
+            .with_session("abc");
+        let puzzle = Puzzle::new(2025, 1);
+
+        let mut ledger = Ledger::open(&path)?;
+        assert_eq!(
+            Verdict::TooHigh,
+            client.submit(
+                puzzle,
+                Part::One,
+                "42",
+                &mut ledger
+            )?
+        );
+
+        // a fresh ledger reads the recorded verdict back,
+        // the server only answers once so a second request
+        // would fail
+        let mut ledger = Ledger::open(&path)?;
+        for answer in ["42", "50"] {
+            let error = client
+                .submit(
+                    puzzle,
+                    Part::One,
+                    answer,
+                    &mut ledger,
+                )
+                .unwrap_err();
+            assert!(matches!(
+                error,
+                Error::AlreadySubmitted {
+                    verdict: Verdict::TooHigh,
+                    ..
+                }
+            ));
+        }
+        assert_eq!(1, server.requests().len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rate_limited_is_not_recorded()
+    -> miette::Result<()> {
+        let dir = tempfile::tempdir().unwrap();
+        let mut ledger =
+            Ledger::open(dir.path().join("ledger.tsv"))?;
+        let server = TestServer::new(vec![(
+            200,
+            &page(
+                "You gave an answer too recently; You have \
+                 34s left to wait.",
+            ),
+        )]);
+        let client = Client::new(&server.base_url)
+            .with_session("abc");
+
+        let verdict = client.submit(
+            Puzzle::new(2025, 1),
+            Part::One,
+            "42",
+            &mut ledger,
+        )?;
+        assert_eq!(
+            Verdict::RateLimited {
+                wait: Some(Duration::from_secs(34))
+            },
+            verdict
+        );
+        assert!(ledger.entries().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!("6%2C0", form_encode("6,0"));
+    }
+}
@@ -0,0 +1,91 @@
+//! A stand-in for adventofcode.com that answers a
+//! fixed list of responses, one per connection, and
+//! records the requests it received.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+pub struct TestServer {
+    pub base_url: String,
+    requests: Receiver<String>,
+}
+
+impl TestServer {
+    /// Answers each connection with the next
+    /// `(status, body)`, then stops listening
+    pub fn new(responses: Vec<(u16, &str)>) -> Self {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!(
+            "http://{}",
+            listener.local_addr().unwrap()
+        );
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| {
+                (status, body.to_string())
+            })
+            .collect::<Vec<_>>();
+
+        let (sender, requests) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) =
+                    listener.accept().unwrap();
+                let request = read_request(&mut stream);
+                sender.send(request).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Test\r\n\
+                     Content-Type: text/html\r\n\
+                     Content-Length: {}\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// The next request, headers and body
+    pub fn request(&self) -> String {
+        self.requests
+            .recv_timeout(Duration::from_secs(5))
+            .expect("the client sent a request")
+    }
+
+    /// Every request received so far
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.try_iter().collect()
+    }
+}
+
+fn read_request(stream: &mut impl Read) -> String {
+    let mut reader = BufReader::new(stream);
+    let mut request = String::new();
+    let mut content_length = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap();
+        }
+        request.push_str(&line);
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    request.push_str(&String::from_utf8(body).unwrap());
+    request
+}
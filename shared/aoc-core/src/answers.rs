@@ -0,0 +1,169 @@
+use crate::Part;
+use miette::{
+    IntoDiagnostic, LabeledSpan, NamedSource, miette,
+};
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item, Table, value};
+
+/// The accepted answers for the real inputs, kept at
+/// the workspace root
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Accepted answers per day and part, as
+///
+/// ```toml
+/// [day-01]
+/// part1 = "1150"
+/// part2 = "6738"
+/// ```
+///
+/// Comments and formatting are kept when answers
+/// are added.
+#[derive(Debug)]
+pub struct KnownAnswers {
+    path: PathBuf,
+    document: DocumentMut,
+}
+
+impl KnownAnswers {
+    /// Reads the answers at `path`, which doesn't
+    /// need to exist yet
+    pub fn load(
+        path: impl Into<PathBuf>,
+    ) -> miette::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path)
+        {
+            Ok(contents) => contents,
+            Err(e)
+                if e.kind()
+                    == std::io::ErrorKind::NotFound =>
+            {
+                String::new()
+            }
+            Err(e) => {
+                return Err(miette!(
+                    "could not read {}: {e}",
+                    path.display()
+                ));
+            }
+        };
+
+        let document = contents
+            .parse::<DocumentMut>()
+            .map_err(|e| {
+                miette!(
+                    code = "aoc::answers",
+                    labels = e
+                        .span()
+                        .map(|span| {
+                            LabeledSpan::at(span, "here")
+                        })
+                        .into_iter()
+                        .collect::<Vec<_>>(),
+                    "{} is not valid toml, {}",
+                    path.display(),
+                    e.message()
+                )
+                .with_source_code(
+                    NamedSource::new(
+                        path.display().to_string(),
+                        contents.clone(),
+                    ),
+                )
+            })?;
+        Ok(Self { path, document })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The accepted answer, numbers are accepted as
+    /// well as strings
+    pub fn get(
+        &self,
+        day: &str,
+        part: Part,
+    ) -> Option<String> {
+        let answer = self
+            .document
+            .get(day)?
+            .get(part.to_string())?;
+        answer.as_str().map(str::to_string).or_else(|| {
+            answer
+                .as_integer()
+                .map(|answer| answer.to_string())
+        })
+    }
+
+    pub fn insert(
+        &mut self,
+        day: &str,
+        part: Part,
+        answer: &str,
+    ) {
+        let day = self
+            .document
+            .entry(day)
+            .or_insert(Item::Table(Table::new()));
+        day[part.to_string().as_str()] = value(answer);
+    }
+
+    pub fn save(&self) -> miette::Result<()> {
+        std::fs::write(
+            &self.path,
+            self.document.to_string(),
+        )
+        .into_diagnostic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_keeps_comments() -> miette::Result<()> {
+        let dir = tempfile::tempdir().into_diagnostic()?;
+        let path = dir.path().join("answers.toml");
+        std::fs::write(
+            &path,
+            "# accepted answers\n[day-01]\npart1 = 1150\n",
+        )
+        .into_diagnostic()?;
+
+        let mut answers = KnownAnswers::load(&path)?;
+        assert_eq!(
+            Some("1150".to_string()),
+            answers.get("day-01", Part::One)
+        );
+        assert_eq!(None, answers.get("day-01", Part::Two));
+
+        answers.insert("day-01", Part::Two, "6738");
+        answers.insert("day-02", Part::One, "12");
+        answers.save()?;
+        assert_eq!(
+            "# accepted answers
+[day-01]
+part1 = 1150
+part2 = \"6738\"
+
+[day-02]
+part1 = \"12\"
+",
+            std::fs::read_to_string(&path)
+                .into_diagnostic()?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.toml");
+        std::fs::write(&path, "[day-01\npart1 = 1")
+            .unwrap();
+        assert!(KnownAnswers::load(&path).is_err());
+    }
+}
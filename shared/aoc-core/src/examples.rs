@@ -0,0 +1,235 @@
+use crate::Part;
+use miette::{IntoDiagnostic, miette};
+use std::path::Path;
+
+/// Directory in each day holding the examples from
+/// the puzzle description
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// An example input and the answer the puzzle
+/// description gives for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// the file stem, `part1` or `part2_larger`
+    pub name: String,
+    pub input: String,
+    pub answer: String,
+}
+
+impl Example {
+    /// Writes `examples/<name>.txt` and
+    /// `examples/<name>.answer` into `day_dir`
+    pub fn write(
+        &self,
+        day_dir: &Path,
+    ) -> miette::Result<()> {
+        let dir = day_dir.join(EXAMPLES_DIR);
+        std::fs::create_dir_all(&dir).into_diagnostic()?;
+        std::fs::write(
+            dir.join(format!("{}.txt", self.name)),
+            &self.input,
+        )
+        .into_diagnostic()?;
+        std::fs::write(
+            dir.join(format!("{}.answer", self.name)),
+            format!("{}\n", self.answer),
+        )
+        .into_diagnostic()
+    }
+}
+
+/// Every example for `part` in the day in `day_dir`,
+/// each `examples/part<N>*.txt` with an `.answer`
+/// next to it.
+///
+/// ```no_run
+/// # use aoc_core::{Part, examples};
+/// # fn process(_: &str) -> miette::Result<String> { Ok("".into()) }
+/// for example in
+///     examples::load(env!("CARGO_MANIFEST_DIR"), Part::One)?
+/// {
+///     assert_eq!(example.answer, process(&example.input)?);
+/// }
+/// # Ok::<(), miette::Report>(())
+/// ```
+pub fn load(
+    day_dir: &str,
+    part: Part,
+) -> miette::Result<Vec<Example>> {
+    let dir = Path::new(day_dir).join(EXAMPLES_DIR);
+    let day = Path::new(day_dir)
+        .file_name()
+        .map(|day| day.to_string_lossy())
+        .unwrap_or("day-XX".into());
+    let missing = || {
+        miette!(
+            code = "aoc::examples",
+            help = format!(
+                "scrape them from the puzzle with `just \
+                 examples {day}`, or add `{part}.txt` and \
+                 `{part}.answer` by hand"
+            ),
+            "no examples for {day} {part} in {}",
+            dir.display()
+        )
+    };
+
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Err(missing());
+    };
+    let mut examples = vec![];
+    for entry in entries {
+        let path = entry.into_diagnostic()?.path();
+        let Some(name) = path
+            .file_stem()
+            .map(|name| name.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        if !name.starts_with(&part.to_string())
+            || path
+                .extension()
+                .is_none_or(|ext| ext != "txt")
+        {
+            continue;
+        }
+
+        examples.push(read(&path)?);
+    }
+
+    if examples.is_empty() {
+        return Err(missing());
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+/// The example in `examples/<name>.txt`, with the
+/// answer from `<name>.answer` next to it
+pub fn read(path: &Path) -> miette::Result<Example> {
+    let name = path
+        .file_stem()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| {
+            miette!("{} isn't an example", path.display())
+        })?;
+    let answer = std::fs::read_to_string(
+        path.with_extension("answer"),
+    )
+    .map_err(|e| {
+        miette!(
+            "{name}.txt has no {name}.answer next to it: {e}"
+        )
+    })?;
+    Ok(Example {
+        name,
+        input: std::fs::read_to_string(path)
+            .into_diagnostic()?,
+        answer: answer.trim().to_string(),
+    })
+}
+
+/// An rstest `#[files]` case of the template's part
+/// tests. The examples directory itself is a case too,
+/// the template ships it empty, so each part has one
+/// before its examples are scraped. That case prints a
+/// notice when `part` has no examples and gives
+/// `None`.
+///
+/// ```no_run
+/// # use aoc_core::{Part, examples};
+/// # fn process(_: &str) -> miette::Result<String> { Ok("".into()) }
+/// # let path = std::path::Path::new("examples/part2.txt");
+/// if let Some(example) = examples::case(path, Part::Two)? {
+///     assert_eq!(example.answer, process(&example.input)?);
+/// }
+/// # Ok::<(), miette::Report>(())
+/// ```
+pub fn case(
+    path: &Path,
+    part: Part,
+) -> miette::Result<Option<Example>> {
+    if !path.is_dir() {
+        return read(path).map(Some);
+    }
+    let day_dir = path.parent().unwrap_or(path);
+    if let Err(report) =
+        load(&day_dir.to_string_lossy(), part)
+    {
+        eprintln!(
+            "skipping the {part} examples: {report:?}"
+        );
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() -> miette::Result<()> {
+        let dir = tempfile::tempdir().into_diagnostic()?;
+        let day_dir = dir.path().join("day-06");
+        let example = |name: &str, answer: &str| Example {
+            name: name.to_string(),
+            input: "123 328\n 45 64\n*   +\n".to_string(),
+            answer: answer.to_string(),
+        };
+        example("part1", "4277556").write(&day_dir)?;
+        example("part2", "3263827").write(&day_dir)?;
+        example("part2_larger", "1").write(&day_dir)?;
+
+        let day_dir = day_dir.to_str().unwrap();
+        assert_eq!(
+            vec![example("part1", "4277556")],
+            load(day_dir, Part::One)?
+        );
+        assert_eq!(
+            vec![
+                example("part2", "3263827"),
+                example("part2_larger", "1")
+            ],
+            load(day_dir, Part::Two)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_case() -> miette::Result<()> {
+        let dir = tempfile::tempdir().into_diagnostic()?;
+        let day_dir = dir.path().join("day-01");
+        let example = Example {
+            name: "part1".to_string(),
+            input: "L68\n".to_string(),
+            answer: "3".to_string(),
+        };
+        example.write(&day_dir)?;
+
+        let examples_dir = day_dir.join(EXAMPLES_DIR);
+        assert_eq!(
+            Some(example),
+            case(
+                &examples_dir.join("part1.txt"),
+                Part::One
+            )?
+        );
+        // part 2 has no examples, its case skips
+        assert_eq!(None, case(&examples_dir, Part::Two)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let report =
+            load("/does/not/exist/day-42", Part::One)
+                .unwrap_err();
+        assert!(
+            report
+                .help()
+                .unwrap()
+                .to_string()
+                .contains("just examples day-42")
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::Part;
+use miette::{IntoDiagnostic, NamedSource, miette};
+use std::{
+    io::Read,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+/// Directory holding every day's inputs as
+/// `<day>/input<N>.txt`, used instead of the day's
+/// own directory when set
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+/// Passing `-` as the input path reads from stdin
+pub const STDIN: &str = "-";
+
+/// `input1.txt` or `input2.txt` for the day in
+/// `day_dir`, looked up in `$AOC_INPUTS/<day>/`
+/// when it is set
+pub fn default_path(day_dir: &Path, part: Part) -> PathBuf {
+    let filename = format!("input{}.txt", part.number());
+    match std::env::var_os(INPUTS_DIR_VAR) {
+        Some(inputs_dir) => {
+            let day = day_dir
+                .file_name()
+                .unwrap_or(day_dir.as_os_str());
+            PathBuf::from(inputs_dir)
+                .join(day)
+                .join(filename)
+        }
+        None => day_dir.join(filename),
+    }
+}
+
+/// Reads puzzle input from `path`, or from stdin
+/// when `path` is `-`
+pub fn read(path: &Path) -> miette::Result<String> {
+    if path.as_os_str() == STDIN {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .into_diagnostic()?;
+        return Ok(input);
+    }
+
+    std::fs::read_to_string(path).map_err(|e| {
+        let day = path
+            .parent()
+            .and_then(Path::file_name)
+            .map(|day| day.to_string_lossy())
+            .unwrap_or("day-XX".into());
+        miette!(
+            code = "aoc::input",
+            help = format!(
+                "fetch it with `just get-input {day}`, \
+                 pass a path (or `-` for stdin), or set \
+                 `{INPUTS_DIR_VAR}` to a directory of \
+                 inputs"
+            ),
+            "could not read puzzle input `{}`: {e}",
+            path.display()
+        )
+    })
+}
+
+/// Puzzle input along with the file it was read
+/// from, derefs to the text
+#[derive(Debug, Clone)]
+pub struct Input {
+    name: String,
+    text: String,
+}
+
+impl Input {
+    /// Input named after the file at `path`, or
+    /// `stdin` for `-`
+    pub fn new(path: &Path, text: String) -> Self {
+        let name = if path.as_os_str() == STDIN {
+            "stdin".to_string()
+        } else {
+            path.display().to_string()
+        };
+        Self { name, text }
+    }
+
+    /// Reads the input like [`read`]
+    pub fn load(path: &Path) -> miette::Result<Self> {
+        Ok(Self::new(path, read(path)?))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Attaches the input to an error as its source
+    /// code, so the labels of parse errors render
+    /// against the file. Errors with their own source
+    /// code keep it.
+    pub fn with_source<T>(
+        &self,
+        result: miette::Result<T>,
+    ) -> miette::Result<T> {
+        result.map_err(|report| {
+            report.with_source_code(NamedSource::new(
+                &self.name,
+                self.text.clone(),
+            ))
+        })
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Input for a day's `src/bin/*.rs`. The first
+/// argument is used as the path when given.
+///
+/// ```no_run
+/// # use aoc_core::{Part, input};
+/// let file = input::from_args(
+///     env!("CARGO_MANIFEST_DIR"),
+///     Part::One,
+/// )?;
+/// # Ok::<(), miette::Report>(())
+/// ```
+pub fn from_args(
+    day_dir: &str,
+    part: Part,
+) -> miette::Result<Input> {
+    let path = std::env::args_os()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            default_path(Path::new(day_dir), part)
+        });
+    Input::load(&path)
+}
+
+/// The divan runner for a day's
+/// `benches/benchmarks.rs`. `load` is called for each
+/// part with an input, to read it before anything is
+/// timed. A part without one, like on a fresh
+/// checkout, prints a notice and its benchmarks are
+/// skipped, the parse ones go with part 1.
+///
+/// ```no_run
+/// # use aoc_core::{Part, input};
+/// input::bench_divan(env!("CARGO_MANIFEST_DIR"), |part| {
+///     // force the part's inputs
+/// })
+/// .main();
+/// ```
+pub fn bench_divan(
+    day_dir: &str,
+    mut load: impl FnMut(Part),
+) -> divan::Divan {
+    let mut divan = divan::Divan::from_args();
+    for part in [Part::One, Part::Two] {
+        let path = default_path(Path::new(day_dir), part);
+        if path.exists() {
+            load(part);
+            continue;
+        }
+        eprintln!(
+            "no {}, skipping the {part} benchmarks",
+            path.display()
+        );
+        divan = divan.skip_regex(match part {
+            Part::One => "parse|part1",
+            Part::Two => "part2",
+        });
+    }
+    divan
+}
+
+/// Input for a day's `benches/benchmarks.rs`.
+///
+/// divan owns the command line, so only the
+/// default path is used. A missing input prints
+/// the report and exits instead of panicking in
+/// the middle of a benchmark, [`bench_divan`] skips
+/// the benchmarks that would read it.
+pub fn for_bench(day_dir: &str, part: Part) -> String {
+    match read(&default_path(Path::new(day_dir), part)) {
+        Ok(input) => input,
+        Err(report) => {
+            eprintln!("{report:?}");
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_missing_input() {
+        let report = read(Path::new(
+            "/does/not/exist/day-42/input1.txt",
+        ))
+        .unwrap_err();
+        assert!(
+            report
+                .help()
+                .unwrap()
+                .to_string()
+                .contains("just get-input day-42")
+        );
+    }
+
+    #[test]
+    fn test_read_input() -> miette::Result<()> {
+        let dir = tempfile::tempdir().into_diagnostic()?;
+        let path = dir.path().join("input1.txt");
+        std::fs::write(&path, "L68\nL30")
+            .into_diagnostic()?;
+        assert_eq!("L68\nL30", read(&path)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_source() {
+        let input = Input::new(
+            Path::new("day-01/input1.txt"),
+            "L68\nX30".to_string(),
+        );
+        let report = input
+            .with_source::<()>(Err(miette!(
+                labels = vec![miette::LabeledSpan::at(
+                    4..5,
+                    "here"
+                )],
+                "expected `L` or `R`"
+            )))
+            .unwrap_err();
+        let source = report.source_code().unwrap();
+        let span =
+            source.read_span(&(4..5).into(), 0, 0).unwrap();
+        assert_eq!(Some("day-01/input1.txt"), span.name());
+        assert_eq!(b"X", span.data());
+        assert_eq!(
+            "stdin",
+            Input::new(Path::new("-"), String::new())
+                .name()
+        );
+    }
+}
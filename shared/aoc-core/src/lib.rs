@@ -0,0 +1,9 @@
+pub mod answers;
+pub mod examples;
+pub mod input;
+pub mod registry;
+pub mod solver;
+pub mod variants;
+
+pub use registry::{Day, Part, Registry, Solution};
+pub use solver::Solver;
@@ -0,0 +1,270 @@
+use miette::miette;
+use std::{fmt, str::FromStr};
+
+/// The signature every `partN::process` shares
+pub type Process = fn(&str) -> miette::Result<String>;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// the number used in file and module names,
+    /// `input1.txt`, `part2.rs`, etc
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "part{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "part1" | "1" => Ok(Part::One),
+            "part2" | "2" => Ok(Part::Two),
+            other => Err(format!(
+                "part `{other}` must be `part1` or `part2`"
+            )),
+        }
+    }
+}
+
+/// A single `process` function for a day's part.
+///
+/// The main solution for a part has no variant,
+/// alternative implementations such as
+/// `part2_convolve` are registered under the
+/// module suffix, `convolve`.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub part: Part,
+    pub variant: Option<&'static str>,
+    pub process: Process,
+}
+
+impl Solution {
+    pub const fn new(part: Part, process: Process) -> Self {
+        Self {
+            part,
+            variant: None,
+            process,
+        }
+    }
+
+    pub const fn variant(self, name: &'static str) -> Self {
+        Self {
+            variant: Some(name),
+            ..self
+        }
+    }
+
+    /// `part2`, or `part2_convolve` for a variant,
+    /// matching the module the solution lives in
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(variant) => {
+                format!("{}_{variant}", self.part)
+            }
+            None => self.part.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for Solution {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("Solution")
+            .field("part", &self.part)
+            .field("variant", &self.variant)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Everything a day crate exports to the runner.
+///
+/// Each day defines this in its `lib.rs`:
+///
+/// ```
+/// use aoc_core::{Day, Part, Solution};
+/// # fn process(_: &str) -> miette::Result<String> { Ok("".into()) }
+///
+/// pub const DAY: Day = Day {
+///     name: "day-04",
+///     solutions: &[
+///         Solution::new(Part::One, process),
+///         Solution::new(Part::Two, process),
+///         Solution::new(Part::Two, process).variant("convolve"),
+///     ],
+/// };
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    /// formatted as `day-01` to match the crate names
+    pub name: &'static str,
+    pub solutions: &'static [Solution],
+}
+
+impl Day {
+    pub fn solutions_for(
+        &self,
+        part: Part,
+    ) -> impl Iterator<Item = &Solution> {
+        self.solutions
+            .iter()
+            .filter(move |solution| solution.part == part)
+    }
+}
+
+/// Every day in the workspace, used to look up a
+/// solution by day, part and variant.
+#[derive(Debug, Clone, Copy)]
+pub struct Registry {
+    days: &'static [Day],
+}
+
+impl Registry {
+    pub const fn new(days: &'static [Day]) -> Self {
+        Self { days }
+    }
+
+    pub fn days(&self) -> &'static [Day] {
+        self.days
+    }
+
+    pub fn day(&self, name: &str) -> miette::Result<&Day> {
+        self.days
+            .iter()
+            .find(|day| day.name == name)
+            .ok_or_else(|| {
+                miette!(
+                    help = format!(
+                        "registered days are: {}",
+                        self.days
+                            .iter()
+                            .map(|day| day.name)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                    "no day named `{name}` is registered"
+                )
+            })
+    }
+
+    pub fn solution(
+        &self,
+        day: &str,
+        part: Part,
+        variant: Option<&str>,
+    ) -> miette::Result<&Solution> {
+        let day = self.day(day)?;
+        day.solutions_for(part)
+            .find(|solution| solution.variant == variant)
+            .ok_or_else(|| {
+                let available = day
+                    .solutions_for(part)
+                    .map(|solution| {
+                        solution
+                            .variant
+                            .unwrap_or("(default)")
+                    })
+                    .collect::<Vec<_>>();
+                miette!(
+                    help = if available.is_empty() {
+                        format!(
+                            "{} has no {part} yet",
+                            day.name
+                        )
+                    } else {
+                        format!(
+                            "available variants are: {}",
+                            available.join(", ")
+                        )
+                    },
+                    "{} {part} has no variant `{}`",
+                    day.name,
+                    variant.unwrap_or("(default)")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn one(_input: &str) -> miette::Result<String> {
+        Ok("1".to_string())
+    }
+
+    fn two(_input: &str) -> miette::Result<String> {
+        Ok("2".to_string())
+    }
+
+    const REGISTRY: Registry = Registry::new(&[Day {
+        name: "day-01",
+        solutions: &[
+            Solution::new(Part::One, one),
+            Solution::new(Part::Two, one),
+            Solution::new(Part::Two, two).variant("fast"),
+        ],
+    }]);
+
+    #[rstest]
+    #[case("1", Part::One, None)]
+    #[case("1", Part::Two, None)]
+    #[case("2", Part::Two, Some("fast"))]
+    fn test_solution(
+        #[case] expected: &str,
+        #[case] part: Part,
+        #[case] variant: Option<&str>,
+    ) -> miette::Result<()> {
+        let solution =
+            REGISTRY.solution("day-01", part, variant)?;
+        assert_eq!(expected, (solution.process)("")?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("day-02", Part::One, None)]
+    #[case("day-01", Part::One, Some("fast"))]
+    fn test_missing_solution(
+        #[case] day: &str,
+        #[case] part: Part,
+        #[case] variant: Option<&str>,
+    ) {
+        assert!(
+            REGISTRY.solution(day, part, variant).is_err()
+        );
+    }
+
+    #[rstest]
+    #[case(Part::One, "part1")]
+    #[case(Part::Two, "part2")]
+    #[case(Part::Two, "2")]
+    fn test_part_from_str(
+        #[case] expected: Part,
+        #[case] input: &str,
+    ) {
+        assert_eq!(Ok(expected), input.parse());
+    }
+}
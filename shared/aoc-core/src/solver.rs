@@ -0,0 +1,55 @@
+use crate::Part;
+
+/// A day's puzzle split into its stages, so the
+/// input is parsed once and shared by both parts
+/// and each stage can be benchmarked on its own.
+///
+/// ```
+/// use aoc_core::{Part, Solver};
+///
+/// struct Day00;
+///
+/// impl Solver for Day00 {
+///     type Parsed = Vec<u32>;
+///
+///     fn parse(input: &str) -> miette::Result<Vec<u32>> {
+///         Ok(input.lines().map(|l| l.len() as u32).collect())
+///     }
+///
+///     fn part1(lines: &Vec<u32>) -> miette::Result<String> {
+///         Ok(lines.iter().sum::<u32>().to_string())
+///     }
+///
+///     fn part2(lines: &Vec<u32>) -> miette::Result<String> {
+///         Ok(lines.iter().max().unwrap_or(&0).to_string())
+///     }
+/// }
+///
+/// assert_eq!("5", Day00::run("ab\ncde", Part::One)?);
+/// # Ok::<(), miette::Report>(())
+/// ```
+pub trait Solver {
+    type Parsed;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed>;
+
+    fn part1(
+        parsed: &Self::Parsed,
+    ) -> miette::Result<String>;
+
+    fn part2(
+        parsed: &Self::Parsed,
+    ) -> miette::Result<String>;
+
+    /// parse the input and solve a single part
+    fn run(
+        input: &str,
+        part: Part,
+    ) -> miette::Result<String> {
+        let parsed = Self::parse(input)?;
+        match part {
+            Part::One => Self::part1(&parsed),
+            Part::Two => Self::part2(&parsed),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Day, Part};
+use miette::miette;
+use std::fmt;
+
+/// The answer every variant of a part gave for the
+/// same input
+#[derive(Debug)]
+pub struct Answers {
+    pub day: &'static str,
+    pub part: Part,
+    /// `(name, answer)` for each variant, in
+    /// registration order
+    pub answers: Vec<(String, miette::Result<String>)>,
+}
+
+impl Answers {
+    /// The answer all variants agree on, `None`
+    /// when any of them disagrees or fails
+    pub fn agreed(&self) -> Option<&str> {
+        let mut answers = self
+            .answers
+            .iter()
+            .map(|(_, answer)| answer.as_ref().ok());
+        let first = answers.next()??;
+        answers
+            .all(|answer| answer == Some(first))
+            .then_some(first.as_str())
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for (name, answer) in self.answers.iter() {
+            match answer {
+                Ok(answer) => writeln!(
+                    f,
+                    "{} {name}: {answer}",
+                    self.day
+                )?,
+                Err(report) => writeln!(
+                    f,
+                    "{} {name}: failed, {report}",
+                    self.day
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs every registered variant of `part` on the
+/// same input
+pub fn run_variants(
+    day: &Day,
+    part: Part,
+    input: &str,
+) -> Answers {
+    Answers {
+        day: day.name,
+        part,
+        answers: day
+            .solutions_for(part)
+            .map(|solution| {
+                (
+                    solution.name(),
+                    (solution.process)(input),
+                )
+            })
+            .collect(),
+    }
+}
+
+/// Runs every variant of `part` and returns the
+/// answer they agree on, or an error listing what
+/// each variant produced.
+pub fn check_variants(
+    day: &Day,
+    part: Part,
+    input: &str,
+) -> miette::Result<String> {
+    let answers = run_variants(day, part, input);
+    match answers.agreed() {
+        Some(answer) => Ok(answer.to_string()),
+        None if answers.answers.is_empty() => Err(miette!(
+            "{} has no {part} registered",
+            day.name
+        )),
+        None => Err(miette!(
+            code = "aoc::variants::disagree",
+            help = "a variant changed its answer, compare \
+                    it against the main solution",
+            "{} {part} variants disagree\n{answers}",
+            day.name
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solution;
+
+    fn one(_input: &str) -> miette::Result<String> {
+        Ok("1".to_string())
+    }
+
+    fn two(_input: &str) -> miette::Result<String> {
+        Ok("2".to_string())
+    }
+
+    fn fails(_input: &str) -> miette::Result<String> {
+        Err(miette!("oh no"))
+    }
+
+    const DAY: Day = Day {
+        name: "day-01",
+        solutions: &[
+            Solution::new(Part::One, one),
+            Solution::new(Part::One, one).variant("same"),
+            Solution::new(Part::Two, one),
+            Solution::new(Part::Two, two)
+                .variant("different"),
+            Solution::new(Part::Two, fails)
+                .variant("fails"),
+        ],
+    };
+
+    #[test]
+    fn test_variants_agree() -> miette::Result<()> {
+        assert_eq!(
+            "1",
+            check_variants(&DAY, Part::One, "")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_variants_disagree() {
+        let answers = run_variants(&DAY, Part::Two, "");
+        assert_eq!(None, answers.agreed());
+        assert_eq!(
+            "day-01 part2: 1
+day-01 part2_different: 2
+day-01 part2_fails: failed, oh no
+",
+            answers.to_string()
+        );
+        assert!(
+            check_variants(&DAY, Part::Two, "").is_err()
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::DisjointSet;
+use std::{collections::HashMap, hash::Hash};
+
+/// A [`DisjointSet`] over hashable values, for when
+/// the elements aren't already numbered
+#[derive(Debug, Clone)]
+pub struct Keyed<T> {
+    indices: HashMap<T, usize>,
+    items: Vec<T>,
+    set: DisjointSet,
+}
+
+impl<T> Default for Keyed<T> {
+    fn default() -> Self {
+        Self {
+            indices: HashMap::new(),
+            items: Vec::new(),
+            set: DisjointSet::default(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> Keyed<T> {
+    /// Adds `item` as a singleton set, or returns the
+    /// index it was inserted at before
+    pub fn insert(&mut self, item: T) -> usize {
+        if let Some(&index) = self.indices.get(&item) {
+            return index;
+        }
+        let index = self.set.push();
+        self.indices.insert(item.clone(), index);
+        self.items.push(item);
+        index
+    }
+
+    pub fn index_of(&self, item: &T) -> Option<usize> {
+        self.indices.get(item).copied()
+    }
+
+    /// The item inserted at `index`
+    pub fn item(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+
+    /// The number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.set.count()
+    }
+
+    /// The index of the root of `item`'s set, `None`
+    /// if it wasn't inserted
+    pub fn find(&mut self, item: &T) -> Option<usize> {
+        let index = self.index_of(item)?;
+        Some(self.set.find(index))
+    }
+
+    /// Merges the sets of `a` and `b`, `None` if either
+    /// wasn't inserted and `Some(false)` if they
+    /// already were the same set
+    pub fn union(&mut self, a: &T, b: &T) -> Option<bool> {
+        let (a, b) = (self.index_of(a)?, self.index_of(b)?);
+        Some(self.set.union(a, b))
+    }
+
+    pub fn same_set(&mut self, a: &T, b: &T) -> bool {
+        matches!(
+            (self.find(a), self.find(b)),
+            (Some(a), Some(b)) if a == b
+        )
+    }
+
+    /// The size of `item`'s set
+    pub fn size_of(&mut self, item: &T) -> Option<usize> {
+        let index = self.index_of(item)?;
+        Some(self.set.size_of(index))
+    }
+
+    /// The members of every set, in insertion order
+    pub fn components(&mut self) -> Vec<Vec<&T>> {
+        self.set
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| &self.items[index])
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The underlying index-based set, read only so
+    /// it can't grow apart from the items. Merge through
+    /// [`Keyed::union`].
+    pub fn set(&self) -> &DisjointSet {
+        &self.set
+    }
+}
+
+impl<T: Eq + Hash + Clone> FromIterator<T> for Keyed<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Self {
+        let mut keyed = Self::default();
+        for item in iter {
+            keyed.insert(item);
+        }
+        keyed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyed() {
+        let mut keyed: Keyed<&str> =
+            ["a", "b", "c", "a"].into_iter().collect();
+        assert_eq!(3, keyed.count());
+        assert_eq!(Some(true), keyed.union(&"c", &"a"));
+        assert_eq!(None, keyed.union(&"a", &"z"));
+        assert!(keyed.same_set(&"a", &"c"));
+        assert!(!keyed.same_set(&"a", &"b"));
+        assert_eq!(Some(2), keyed.size_of(&"c"));
+        assert_eq!(
+            vec![vec![&"a", &"c"], vec![&"b"]],
+            keyed.components()
+        );
+        assert_eq!(3, keyed.set().len());
+        assert_eq!(vec![1, 2], keyed.set().set_sizes());
+    }
+}
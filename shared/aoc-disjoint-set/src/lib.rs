@@ -0,0 +1,196 @@
+//! Union-find over the indices `0..len`, see
+//! [`Keyed`] to union arbitrary hashable values.
+
+mod keyed;
+
+pub use keyed::Keyed;
+
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSet {
+    /// roots are their own parent
+    parents: Vec<usize>,
+    /// only up to date for roots
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// `len` singleton sets
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    /// The number of elements
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Adds a singleton set, returning its index
+    pub fn push(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.sizes.push(1);
+        self.count += 1;
+        index
+    }
+
+    /// The root of the set containing `index`. Walks
+    /// up iteratively and then points every visited
+    /// element straight at the root.
+    pub fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        let mut current = index;
+        while self.parents[current] != root {
+            current = std::mem::replace(
+                &mut self.parents[current],
+                root,
+            );
+        }
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`, `false`
+    /// if they already were the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        // the smaller set hangs off the larger one
+        let (root, child) = if self.sizes[a] < self.sizes[b]
+        {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The size of the set containing `index`
+    pub fn size_of(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        self.sizes[root]
+    }
+
+    /// The size of every set, in order of their roots
+    pub fn set_sizes(&self) -> Vec<usize> {
+        self.parents
+            .iter()
+            .enumerate()
+            .filter(|&(index, &parent)| index == parent)
+            .map(|(root, _)| self.sizes[root])
+            .collect()
+    }
+
+    /// The members of every set, each set ordered by
+    /// index and the sets by their lowest member
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut slots = vec![None; self.len()];
+        let mut components: Vec<Vec<usize>> =
+            Vec::with_capacity(self.count);
+        for index in 0..self.len() {
+            let root = self.find(index);
+            let slot =
+                *slots[root].get_or_insert_with(|| {
+                    components.push(Vec::new());
+                    components.len() - 1
+                });
+            components[slot].push(index);
+        }
+        components
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn example() -> DisjointSet {
+        let mut set = DisjointSet::new(6);
+        set.union(0, 2);
+        set.union(4, 2);
+        set.union(1, 5);
+        set
+    }
+
+    #[test]
+    fn test_union() {
+        let mut set = example();
+        assert_eq!(3, set.count());
+        assert!(!set.union(0, 4));
+        assert!(set.union(3, 5));
+        assert_eq!(2, set.count());
+    }
+
+    #[rstest]
+    #[case(0, 4, true)]
+    #[case(2, 2, true)]
+    #[case(1, 5, true)]
+    #[case(0, 1, false)]
+    #[case(3, 5, false)]
+    fn test_same_set(
+        #[case] a: usize,
+        #[case] b: usize,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, example().same_set(a, b));
+    }
+
+    #[test]
+    fn test_sizes() {
+        let mut set = example();
+        assert_eq!(3, set.size_of(4));
+        assert_eq!(1, set.size_of(3));
+        let mut sizes = set.set_sizes();
+        sizes.sort();
+        assert_eq!(vec![1, 2, 3], sizes);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut set = example();
+        let index = set.push();
+        set.union(index, 3);
+        assert_eq!(
+            vec![vec![0, 2, 4], vec![1, 5], vec![3, 6]],
+            set.components()
+        );
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // a recursive find would overflow the stack
+        // walking this before the first compression
+        let len = 1_000_000;
+        let mut set = DisjointSet::new(len);
+        for index in 1..len {
+            set.parents[index] = index - 1;
+        }
+        assert_eq!(0, set.find(len - 1));
+        assert_eq!(0, set.parents[len - 1]);
+    }
+}
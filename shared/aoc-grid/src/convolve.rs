@@ -0,0 +1,202 @@
+use crate::Grid;
+use glam::IVec2;
+use std::ops::{Add, Mul};
+
+/// What a kernel sees past the edge of the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Border {
+    /// nothing, the cells add `A::default()`
+    Zero,
+    /// the opposite edge, as if the grid were tiled
+    Wrap,
+    /// the nearest cell on the edge
+    Clamp,
+}
+
+impl<T: Copy> Grid<T> {
+    /// Centres `kernel` on every cell and sums each
+    /// weight times the cell under it, in `A`. Both
+    /// sides of the kernel need to be odd, `bool`
+    /// cells and weights count as 0 or 1.
+    pub fn convolve<K, A>(
+        &self,
+        kernel: &Grid<K>,
+        border: Border,
+    ) -> Grid<A>
+    where
+        K: Copy,
+        A: Copy
+            + Default
+            + Add<Output = A>
+            + Mul<Output = A>
+            + From<T>
+            + From<K>,
+    {
+        assert!(
+            kernel.width % 2 == 1 && kernel.height % 2 == 1,
+            "kernel sides need to be odd, not {}x{}",
+            kernel.width,
+            kernel.height
+        );
+        let (rx, ry) =
+            (kernel.width / 2, kernel.height / 2);
+        let center = IVec2::new(rx as i32, ry as i32);
+        let mut cells =
+            Vec::with_capacity(self.cells.len());
+        for (position, _) in self.iter() {
+            let (x, y) =
+                (position.x as usize, position.y as usize);
+            let interior = x >= rx
+                && x + rx < self.width
+                && y >= ry
+                && y + ry < self.height;
+            let sum = if interior {
+                // the kernel fits, walk the rows under it
+                kernel.rows().enumerate().fold(
+                    A::default(),
+                    |sum, (ky, weights)| {
+                        let start =
+                            (y + ky - ry) * self.width + x
+                                - rx;
+                        self.cells
+                            [start..start + kernel.width]
+                            .iter()
+                            .zip(weights)
+                            .fold(
+                                sum,
+                                |sum, (&cell, &weight)| {
+                                    sum + A::from(weight)
+                                        * A::from(cell)
+                                },
+                            )
+                    },
+                )
+            } else {
+                kernel.iter().fold(
+                    A::default(),
+                    |sum, (offset, &weight)| match self
+                        .sample(
+                            position + offset - center,
+                            border,
+                        ) {
+                        Some(cell) => {
+                            sum + A::from(weight)
+                                * A::from(cell)
+                        }
+                        None => sum,
+                    },
+                )
+            };
+            cells.push(sum);
+        }
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// The cell `border` puts at `position`, `None`
+    /// past the edge of a zero border
+    fn sample(
+        &self,
+        position: IVec2,
+        border: Border,
+    ) -> Option<T> {
+        let position = match border {
+            Border::Zero => position,
+            Border::Wrap => {
+                position.rem_euclid(self.size())
+            }
+            Border::Clamp => {
+                position.clamp(IVec2::ZERO, self.size() - 1)
+            }
+        };
+        self.get(position).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn ones(width: usize, height: usize) -> Grid<u8> {
+        Grid::new(width, height, 1)
+    }
+
+    fn numbers() -> Grid<u8> {
+        Grid::from_vec(4, (1..=8).collect())
+    }
+
+    #[rstest]
+    #[case(Border::Zero, "4,6,6,4\n4,6,6,4")]
+    #[case(Border::Wrap, "9,9,9,9\n9,9,9,9")]
+    #[case(Border::Clamp, "9,9,9,9\n9,9,9,9")]
+    fn test_box_sum(
+        #[case] border: Border,
+        #[case] expected: &str,
+    ) {
+        let sums = ones(4, 2)
+            .convolve::<u8, u32>(&ones(3, 3), border);
+        assert_eq!(expected, render(&sums));
+    }
+
+    #[rstest]
+    #[case(Border::Zero, "2,3,4,0\n6,7,8,0")]
+    #[case(Border::Wrap, "2,3,4,1\n6,7,8,5")]
+    #[case(Border::Clamp, "2,3,4,4\n6,7,8,8")]
+    fn test_shift(
+        #[case] border: Border,
+        #[case] expected: &str,
+    ) {
+        // picks the cell to the right
+        let kernel = Grid::from_vec(3, vec![0u8, 0, 1]);
+        let shifted =
+            numbers().convolve::<u8, u16>(&kernel, border);
+        assert_eq!(expected, render(&shifted));
+    }
+
+    #[test]
+    fn test_bool_neighbour_count() {
+        let grid =
+            Grid::parse("#.#\n.##\n...\n##.", |c| c == '#')
+                .unwrap();
+        let mut kernel = Grid::new(3, 3, true);
+        kernel[IVec2::ONE] = false;
+        let counts = grid
+            .convolve::<bool, u8>(&kernel, Border::Zero);
+        assert_eq!(
+            "1,4,2\n2,3,2\n3,4,3\n1,1,1",
+            render(&counts)
+        );
+    }
+
+    #[test]
+    fn test_large_kernel_wraps_more_than_once() {
+        let sums = ones(2, 1)
+            .convolve::<u8, u32>(&ones(5, 1), Border::Wrap);
+        assert_eq!("5,5", render(&sums));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "kernel sides need to be odd"
+    )]
+    fn test_even_kernel() {
+        ones(3, 3)
+            .convolve::<u8, u32>(&ones(2, 3), Border::Zero);
+    }
+
+    fn render<T: ToString>(grid: &Grid<T>) -> String {
+        grid.rows()
+            .map(|row| {
+                row.iter()
+                    .map(T::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,445 @@
+//! A dense, row-major grid addressed by `IVec2`
+//! positions, `x` is the column and `y` the row.
+
+mod convolve;
+
+pub use convolve::Border;
+
+use glam::IVec2;
+use miette::{LabeledSpan, miette};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Up, right, down and left
+pub const ORTHOGONAL: [IVec2; 4] =
+    [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+
+/// The orthogonal and diagonal neighbours
+pub const NEIGHBORS: [IVec2; 8] = [
+    IVec2::X,
+    IVec2::Y,
+    IVec2::NEG_X,
+    IVec2::NEG_Y,
+    IVec2::ONE,
+    IVec2::NEG_ONE,
+    IVec2::new(1, -1),
+    IVec2::new(-1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid filled with `value`
+    pub fn new(
+        width: usize,
+        height: usize,
+        value: T,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// A grid of `cells` laid out row by row
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            match width {
+                0 => cells.is_empty(),
+                width => cells.len().is_multiple_of(width),
+            },
+            "{} cells don't fill rows {width} wide",
+            cells.len()
+        );
+        Self {
+            width,
+            height: cells
+                .len()
+                .checked_div(width)
+                .unwrap_or(0),
+            cells,
+        }
+    }
+
+    /// Parses a character map, one row per line.
+    /// Every line needs to be as wide as the first.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> T,
+    ) -> miette::Result<Self> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    // reads like an `aoc_parse`
+                    // diagnostic, that crate builds on
+                    // this one so can't be used here
+                    let start = line.as_ptr() as usize
+                        - input.as_ptr() as usize;
+                    return Err(miette!(
+                        code = "aoc::parse",
+                        labels = vec![LabeledSpan::at(
+                            start..start + line.len(),
+                            "here"
+                        )],
+                        help = format!(
+                            "every row of a grid needs the \
+                             same width, this one is {len} \
+                             wide instead of {width}"
+                        ),
+                        "expected a row as wide as the \
+                         first at line {}, column 1",
+                        y + 1
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The width and height as a position
+    pub fn size(&self) -> IVec2 {
+        IVec2::new(self.width as i32, self.height as i32)
+    }
+
+    /// Whether `position` is inside the grid
+    pub fn contains(&self, position: IVec2) -> bool {
+        (0..self.width as i32).contains(&position.x)
+            && (0..self.height as i32).contains(&position.y)
+    }
+
+    fn index_of(&self, position: IVec2) -> Option<usize> {
+        self.contains(position).then(|| {
+            position.y as usize * self.width
+                + position.x as usize
+        })
+    }
+
+    fn position_of(&self, index: usize) -> IVec2 {
+        IVec2::new(
+            (index % self.width) as i32,
+            (index / self.width) as i32,
+        )
+    }
+
+    pub fn get(&self, position: IVec2) -> Option<&T> {
+        self.index_of(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(
+        &mut self,
+        position: IVec2,
+    ) -> Option<&mut T> {
+        self.index_of(position).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row
+    pub fn positions(
+        &self,
+    ) -> impl Iterator<Item = IVec2> + use<T> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| {
+            (0..width).map(move |x| IVec2::new(x, y))
+        })
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (IVec2, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.position_of(i), cell))
+    }
+
+    /// The first position, row by row, whose cell
+    /// matches
+    pub fn find(
+        &self,
+        predicate: impl FnMut(&T) -> bool,
+    ) -> Option<IVec2> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.position_of(i))
+    }
+
+    /// The up to 4 orthogonal neighbours inside the grid
+    pub fn neighbors4(
+        &self,
+        position: IVec2,
+    ) -> impl Iterator<Item = IVec2> {
+        self.offsets(position, &ORTHOGONAL)
+    }
+
+    /// The up to 8 neighbours inside the grid,
+    /// diagonals included
+    pub fn neighbors8(
+        &self,
+        position: IVec2,
+    ) -> impl Iterator<Item = IVec2> {
+        self.offsets(position, &NEIGHBORS)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        position: IVec2,
+        offsets: &'a [IVec2],
+    ) -> impl Iterator<Item = IVec2> + 'a {
+        offsets
+            .iter()
+            .map(move |offset| position + offset)
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The cells of row `y`
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, an empty grid has no rows
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, top to bottom
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} out of bounds"
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = &T>>
+    {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Swaps rows and columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width: self.height,
+            height: self.width,
+            cells: self
+                .columns()
+                .flatten()
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// A grid of the same size with `f` applied to
+    /// every cell
+    pub fn map<U>(
+        &self,
+        f: impl FnMut(&T) -> U,
+    ) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<IVec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: IVec2) -> &T {
+        self.get(position).unwrap_or_else(|| {
+            panic!("{position} is outside the grid")
+        })
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T> {
+    fn index_mut(&mut self, position: IVec2) -> &mut T {
+        self.get_mut(position).unwrap_or_else(|| {
+            panic!("{position} is outside the grid")
+        })
+    }
+}
+
+/// Renders one line per row, without a trailing
+/// newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const MAP: &str = "\
+#..
+.#.
+..#
+##.";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(MAP, |c| c).unwrap()
+    }
+
+    #[test]
+    fn test_parse() -> miette::Result<()> {
+        let grid = Grid::parse(MAP, |c| c == '#')?;
+        assert_eq!((3, 4), (grid.width(), grid.height()));
+        assert!(grid[IVec2::new(1, 1)]);
+        assert!(!grid[IVec2::new(2, 0)]);
+        assert_eq!(
+            Some(IVec2::new(1, 3)),
+            grid.iter()
+                .filter(|(_, wall)| **wall)
+                .map(|(position, _)| position)
+                .last()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let report =
+            Grid::parse("..\n..\n...", |c| c).unwrap_err();
+        assert_eq!(
+            "expected a row as wide as the first at line 3, column 1",
+            report.to_string()
+        );
+        let label =
+            report.labels().unwrap().next().unwrap();
+        assert_eq!((6, 3), (label.offset(), label.len()));
+    }
+
+    #[rstest]
+    #[case(IVec2::new(0, 0), 2, 3)]
+    #[case(IVec2::new(1, 1), 4, 8)]
+    #[case(IVec2::new(2, 3), 2, 3)]
+    #[case(IVec2::new(1, 3), 3, 5)]
+    fn test_neighbors(
+        #[case] position: IVec2,
+        #[case] four: usize,
+        #[case] eight: usize,
+    ) {
+        let grid = grid();
+        assert_eq!(four, grid.neighbors4(position).count());
+        assert_eq!(
+            eight,
+            grid.neighbors8(position).count()
+        );
+    }
+
+    #[rstest]
+    #[case(IVec2::new(2, 3), true)]
+    #[case(IVec2::new(3, 0), false)]
+    #[case(IVec2::new(0, 4), false)]
+    #[case(IVec2::new(-1, 0), false)]
+    fn test_contains(
+        #[case] position: IVec2,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, grid().contains(position));
+        assert_eq!(
+            expected,
+            grid().get(position).is_some()
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(&['.', '#', '.'], grid.row(1));
+        assert_eq!(
+            "#..#",
+            grid.column(0).collect::<String>()
+        );
+        assert_eq!(4, grid.rows().count());
+        assert_eq!(3, grid.columns().count());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let transposed = grid().transpose();
+        assert_eq!(
+            "#..#\n.#.#\n..#.",
+            transposed.to_string()
+        );
+        assert_eq!(grid(), transposed.transpose());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(MAP, grid().to_string());
+        assert_eq!(
+            "0110\n1010\n1101",
+            grid()
+                .transpose()
+                .map(|&c| u8::from(c == '.'))
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(
+            Some(IVec2::new(1, 1)),
+            grid()
+                .find(|&c| c == '#')
+                .map(|p| p + IVec2::ONE)
+        );
+        assert_eq!(None, grid().find(|&c| c == 'S'));
+    }
+}
@@ -0,0 +1,36 @@
+/// The integers an [`IntervalSet`](crate::IntervalSet)
+/// can hold. Ranges are inclusive, so coalescing needs
+/// the neighbouring values.
+pub trait Bound: Copy + Ord {
+    /// The value right after, `None` at `MAX`
+    fn successor(self) -> Option<Self>;
+
+    /// The value right before, `None` at `MIN`
+    fn predecessor(self) -> Option<Self>;
+
+    /// How many values `start..=end` holds, which
+    /// can be one more than `u64::MAX`
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! bound {
+    ($($t:ty),*) => {$(
+        impl Bound for $t {
+            fn successor(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn predecessor(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(start: Self, end: Self) -> u128 {
+                (end as i128 - start as i128 + 1) as u128
+            }
+        }
+    )*};
+}
+
+bound!(
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize
+);
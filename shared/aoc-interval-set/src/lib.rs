@@ -0,0 +1,301 @@
+//! Sets of integers stored as sorted, inclusive
+//! ranges, overlapping and touching ranges are
+//! coalesced on insert.
+
+mod bound;
+
+pub use bound::Bound;
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// sorted, disjoint and never adjacent
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging it with
+    /// the ranges it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // the first range that doesn't end before
+        // `start` - 1
+        let first =
+            self.ranges.partition_point(|&(_, e)| {
+                e.successor()
+                    .is_some_and(|next| next < start)
+            });
+        // one past the last range starting at or
+        // before `end` + 1
+        let last =
+            self.ranges.partition_point(|&(s, _)| {
+                end.successor().is_none_or(|next| s <= next)
+            });
+        let merged = if first < last {
+            (
+                start.min(self.ranges[first].0),
+                end.max(self.ranges[last - 1].1),
+            )
+        } else {
+            (start, end)
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Binary searches for the range holding `value`
+    pub fn contains(&self, value: T) -> bool {
+        let after = self
+            .ranges
+            .partition_point(|&(s, _)| s <= value);
+        after > 0 && value <= self.ranges[after - 1].1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set
+    pub fn covered(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| T::count(start, end))
+            .sum()
+    }
+
+    /// The coalesced ranges, in order
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    /// The values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (
+            Some(&&(a_start, a_end)),
+            Some(&&(b_start, b_end)),
+        ) = (a.peek(), b.peek())
+        {
+            let (start, end) =
+                (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            // whichever ends first can't overlap anything
+            // further along the other set
+            if a_end < b_end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in `self` but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+        for &(start, end) in &self.ranges {
+            let mut start = Some(start);
+            while let Some(&&(o_start, o_end)) =
+                others.peek()
+            {
+                let Some(current) = start else {
+                    break;
+                };
+                if o_start > end {
+                    break;
+                }
+                if o_end >= current {
+                    if o_start > current {
+                        ranges.push((
+                            current,
+                            o_start.predecessor().expect(
+                                "o_start is above current",
+                            ),
+                        ));
+                    }
+                    start = o_end.successor();
+                }
+                if o_end > end {
+                    break;
+                }
+                others.next();
+            }
+            if let Some(start) = start.filter(|&s| s <= end)
+            {
+                ranges.push((start, end));
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in `bounds` that aren't in the set
+    pub fn complement(
+        &self,
+        bounds: RangeInclusive<T>,
+    ) -> Self {
+        Self::from_iter([bounds]).difference(self)
+    }
+}
+
+impl<T: Bound> Extend<RangeInclusive<T>>
+    for IntervalSet<T>
+{
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(
+        &mut self,
+        iter: I,
+    ) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Bound> FromIterator<RangeInclusive<T>>
+    for IntervalSet<T>
+{
+    fn from_iter<
+        I: IntoIterator<Item = RangeInclusive<T>>,
+    >(
+        iter: I,
+    ) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.iter().map(RangeInclusive::into_inner).collect()
+    }
+
+    #[rstest]
+    #[case(&[(3, 5), (10, 14), (16, 20), (12, 18)], &[(3, 5), (10, 20)])]
+    #[case(&[(1, 2), (3, 4)], &[(1, 4)])]
+    #[case(&[(5, 6), (1, 2), (8, 9), (2, 8)], &[(1, 9)])]
+    #[case(&[(1, 10), (3, 4)], &[(1, 10)])]
+    #[case(&[(4, 3)], &[])]
+    fn test_insert(
+        #[case] inserted: &[(i64, i64)],
+        #[case] expected: &[(i64, i64)],
+    ) {
+        assert_eq!(expected, ranges(&set(inserted)));
+    }
+
+    #[test]
+    fn test_insert_extremes() {
+        let set: IntervalSet<u8> =
+            [250..=255, 0..=3, 4..=249]
+                .into_iter()
+                .collect();
+        assert_eq!(
+            vec![0..=255],
+            set.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(256, set.covered());
+    }
+
+    #[rstest]
+    #[case(1, false)]
+    #[case(5, true)]
+    #[case(8, false)]
+    #[case(11, true)]
+    #[case(17, true)]
+    #[case(32, false)]
+    fn test_contains(
+        #[case] value: i64,
+        #[case] expected: bool,
+    ) {
+        let set =
+            set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(expected, set.contains(value));
+    }
+
+    #[test]
+    fn test_covered() {
+        let set =
+            set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(14, set.covered());
+        assert_eq!(0, IntervalSet::<i64>::new().covered());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(4, 12), (15, 16), (19, 30)]);
+        assert_eq!(vec![(1, 30)], ranges(&a.union(&b)));
+        assert_eq!(
+            vec![(4, 5), (10, 12), (15, 16), (19, 20)],
+            ranges(&a.intersection(&b))
+        );
+        assert_eq!(
+            vec![(1, 3), (13, 14), (17, 18)],
+            ranges(&a.difference(&b))
+        );
+        assert_eq!(
+            vec![(6, 9), (21, 30)],
+            ranges(&b.difference(&a))
+        );
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = set(&[(1, 5), (10, 20)]);
+        assert_eq!(
+            vec![(0, 0), (6, 9), (21, 25)],
+            ranges(&a.complement(0..=25))
+        );
+        assert_eq!(
+            vec![(7, 8)],
+            ranges(&a.complement(7..=8))
+        );
+        assert!(a.complement(2..=4).is_empty());
+
+        let full: IntervalSet<u8> =
+            [0..=255].into_iter().collect();
+        assert!(full.complement(0..=255).is_empty());
+        assert_eq!(
+            vec![0..=255],
+            IntervalSet::<u8>::new()
+                .complement(0..=255)
+                .iter()
+                .collect::<Vec<_>>()
+        );
+    }
+}
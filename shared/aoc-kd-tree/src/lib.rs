@@ -0,0 +1,339 @@
+//! A k-d tree over 3D points, for nearest neighbour
+//! queries and for walking every pair of points closest
+//! first without holding all of them, see [`Pairs`].
+//!
+//! Distances are squared euclidean distances, exact as
+//! long as coordinates stay within ±[`MAX_COORDINATE`].
+
+mod pairs;
+
+pub use pairs::Pairs;
+
+use glam::IVec3;
+use std::collections::BinaryHeap;
+
+/// The largest coordinate the distances are exact for,
+/// either way from 0. Differences fit an `i32` and three
+/// squared differences add up within an `i64`.
+pub const MAX_COORDINATE: i32 = 1 << 29;
+
+#[derive(Debug, Clone)]
+pub struct KdTree {
+    points: Vec<IVec3>,
+    /// indices into `points`. A node owns a range of
+    /// it, the median of the range splits the rest in
+    /// two children.
+    order: Vec<usize>,
+    /// each node's bounding box and split axis, stored
+    /// at the position of its median
+    bounds: Vec<(IVec3, IVec3)>,
+    axes: Vec<usize>,
+}
+
+impl KdTree {
+    pub fn new(points: Vec<IVec3>) -> Self {
+        let len = points.len();
+        let mut tree = Self {
+            points,
+            order: (0..len).collect(),
+            bounds: vec![(IVec3::ZERO, IVec3::ZERO); len],
+            axes: vec![0; len],
+        };
+        tree.build(0, len);
+        tree
+    }
+
+    /// Splits `lo..hi` of `order` at the median of its
+    /// widest axis
+    fn build(&mut self, lo: usize, hi: usize) {
+        if lo >= hi {
+            return;
+        }
+        let (min, max) = self.order[lo..hi].iter().fold(
+            (IVec3::MAX, IVec3::MIN),
+            |(min, max), &index| {
+                let point = self.points[index];
+                (min.min(point), max.max(point))
+            },
+        );
+        let axis = (max - min).max_position();
+        let mid = lo + (hi - lo) / 2;
+        let points = &self.points;
+        self.order[lo..hi].select_nth_unstable_by_key(
+            mid - lo,
+            |&index| points[index][axis],
+        );
+        self.bounds[mid] = (min, max);
+        self.axes[mid] = axis;
+        self.build(lo, mid);
+        self.build(mid + 1, hi);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The points, in the order the tree was built from
+    pub fn points(&self) -> &[IVec3] {
+        &self.points
+    }
+
+    /// The closest point to `query` with its distance
+    pub fn nearest(
+        &self,
+        query: IVec3,
+    ) -> Option<(usize, i64)> {
+        self.k_nearest(query, 1).into_iter().next()
+    }
+
+    /// The `k` closest points to `query` with their
+    /// distances, closest first. Equally distant points
+    /// are ordered by index.
+    pub fn k_nearest(
+        &self,
+        query: IVec3,
+        k: usize,
+    ) -> Vec<(usize, i64)> {
+        let mut nearest = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search_k(
+                0,
+                self.len(),
+                query,
+                k,
+                &mut nearest,
+            );
+        }
+        nearest
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(distance, index)| (index, distance))
+            .collect()
+    }
+
+    /// `nearest` holds the best `k` so far as
+    /// `(distance, index)`, the worst on top
+    fn search_k(
+        &self,
+        lo: usize,
+        hi: usize,
+        query: IVec3,
+        k: usize,
+        nearest: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if nearest.len() == k
+            && nearest.peek().is_some_and(|&(worst, _)| {
+                self.min_distance(mid, query) > worst
+            })
+        {
+            return;
+        }
+        let index = self.order[mid];
+        nearest.push((
+            distance(query, self.points[index]),
+            index,
+        ));
+        if nearest.len() > k {
+            nearest.pop();
+        }
+        for (lo, hi) in self.children(lo, mid, hi, query) {
+            self.search_k(lo, hi, query, k, nearest);
+        }
+    }
+
+    /// The closest point to `origin` other than itself
+    /// that comes after `after` in `(distance, index)`
+    /// order, stepping through its neighbours one by one
+    fn next_neighbor(
+        &self,
+        origin: usize,
+        after: Option<(i64, usize)>,
+    ) -> Option<(i64, usize)> {
+        let mut best = None;
+        self.search_after(
+            0,
+            self.len(),
+            origin,
+            after,
+            &mut best,
+        );
+        best
+    }
+
+    fn search_after(
+        &self,
+        lo: usize,
+        hi: usize,
+        origin: usize,
+        after: Option<(i64, usize)>,
+        best: &mut Option<(i64, usize)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let query = self.points[origin];
+        // nothing in here beats the best so far, or it's
+        // all closer than what was already walked past
+        if best.is_some_and(|(distance, _)| {
+            self.min_distance(mid, query) > distance
+        }) || after.is_some_and(|(distance, _)| {
+            self.max_distance(mid, query) < distance
+        }) {
+            return;
+        }
+        let index = self.order[mid];
+        let candidate = (
+            distance(query, self.points[index]),
+            index,
+        );
+        if index != origin
+            && after.is_none_or(|after| candidate > after)
+            && best.is_none_or(|best| candidate < best)
+        {
+            *best = Some(candidate);
+        }
+        for (lo, hi) in self.children(lo, mid, hi, query) {
+            self.search_after(lo, hi, origin, after, best);
+        }
+    }
+
+    /// The children of the node at `mid`, the side of
+    /// the split `query` is on first
+    fn children(
+        &self,
+        lo: usize,
+        mid: usize,
+        hi: usize,
+        query: IVec3,
+    ) -> [(usize, usize); 2] {
+        let axis = self.axes[mid];
+        let split = self.points[self.order[mid]][axis];
+        if query[axis] < split {
+            [(lo, mid), (mid + 1, hi)]
+        } else {
+            [(mid + 1, hi), (lo, mid)]
+        }
+    }
+
+    /// The least distance from `query` to the bounding
+    /// box of the node at `mid`
+    fn min_distance(
+        &self,
+        mid: usize,
+        query: IVec3,
+    ) -> i64 {
+        let (min, max) = self.bounds[mid];
+        distance(query, query.clamp(min, max))
+    }
+
+    /// The greatest distance from `query` to the
+    /// bounding box of the node at `mid`
+    fn max_distance(
+        &self,
+        mid: usize,
+        query: IVec3,
+    ) -> i64 {
+        let (min, max) = self.bounds[mid];
+        let farthest = IVec3::select(
+            (query - min).abs().cmpgt((query - max).abs()),
+            min,
+            max,
+        );
+        distance(query, farthest)
+    }
+}
+
+/// The squared euclidean distance
+fn distance(a: IVec3, b: IVec3) -> i64 {
+    (a.as_i64vec3() - b.as_i64vec3()).length_squared()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// Points spread over a cube by a small LCG, with
+    /// some repeats
+    pub(crate) fn scattered(len: usize) -> Vec<IVec3> {
+        let mut state = 0x2545_f491_u64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) as i32 % 200 - 100
+        };
+        (0..len)
+            .map(|_| IVec3::new(next(), next(), next()))
+            .collect()
+    }
+
+    #[test]
+    fn test_nearest() {
+        let tree = KdTree::new(vec![
+            IVec3::new(0, 0, 0),
+            IVec3::new(10, 0, 0),
+            IVec3::new(5, 5, 5),
+        ]);
+        assert_eq!(
+            Some((1, 9)),
+            tree.nearest(IVec3::new(7, 0, 0))
+        );
+        assert_eq!(
+            None,
+            KdTree::new(vec![]).nearest(IVec3::ZERO)
+        );
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(5)]
+    #[case(40)]
+    #[case(500)]
+    fn test_k_nearest(#[case] k: usize) {
+        let points = scattered(300);
+        let tree = KdTree::new(points.clone());
+        for query in scattered(20) {
+            let mut expected: Vec<(i64, usize)> = points
+                .iter()
+                .enumerate()
+                .map(|(index, &point)| {
+                    (distance(query, point), index)
+                })
+                .collect();
+            expected.sort_unstable();
+            expected.truncate(k);
+            let expected: Vec<(usize, i64)> = expected
+                .into_iter()
+                .map(|(distance, index)| (index, distance))
+                .collect();
+            assert_eq!(expected, tree.k_nearest(query, k));
+        }
+    }
+
+    #[test]
+    fn test_max_distance() {
+        let tree = KdTree::new(vec![
+            IVec3::new(0, 0, 0),
+            IVec3::new(4, 2, 1),
+        ]);
+        let mid = 1;
+        assert_eq!(
+            16 + 4 + 1,
+            tree.max_distance(mid, IVec3::new(0, 0, 0))
+        );
+        assert_eq!(
+            4 + 1 + 1,
+            tree.max_distance(mid, IVec3::new(2, 1, 0))
+        );
+    }
+}
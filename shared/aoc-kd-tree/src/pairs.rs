@@ -0,0 +1,106 @@
+use crate::KdTree;
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+/// Every pair of points as `(a, b, distance)` with
+/// `a < b`, closest first and equally distant pairs by
+/// index. Only each point's next closest neighbour is
+/// queued, so memory stays linear in the points however
+/// many pairs are taken.
+#[derive(Debug, Clone)]
+pub struct Pairs<'a> {
+    tree: &'a KdTree,
+    /// `(distance, a, b, origin)`, `origin` is the end
+    /// of the pair whose neighbours are being walked
+    queue: BinaryHeap<Reverse<(i64, usize, usize, usize)>>,
+}
+
+impl KdTree {
+    pub fn pairs(&self) -> Pairs<'_> {
+        let mut pairs = Pairs {
+            tree: self,
+            queue: BinaryHeap::with_capacity(self.len()),
+        };
+        for origin in 0..self.len() {
+            pairs.advance(origin, None);
+        }
+        pairs
+    }
+}
+
+impl Pairs<'_> {
+    /// Queues the neighbour of `origin` after `after`
+    fn advance(
+        &mut self,
+        origin: usize,
+        after: Option<(i64, usize)>,
+    ) {
+        if let Some((distance, neighbor)) =
+            self.tree.next_neighbor(origin, after)
+        {
+            self.queue.push(Reverse((
+                distance,
+                origin.min(neighbor),
+                origin.max(neighbor),
+                origin,
+            )));
+        }
+    }
+}
+
+impl Iterator for Pairs<'_> {
+    type Item = (usize, usize, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((distance, a, b, origin)) =
+                self.queue.pop()?;
+            let neighbor = if origin == a { b } else { a };
+            self.advance(
+                origin,
+                Some((distance, neighbor)),
+            );
+            // both ends walk into every pair, only the
+            // lower one reports it
+            if origin == a {
+                return Some((a, b, distance));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{distance, tests::scattered};
+
+    #[test]
+    fn test_all_pairs_in_order() {
+        let points = scattered(250);
+        let mut expected = Vec::new();
+        for a in 0..points.len() {
+            for b in a + 1..points.len() {
+                expected.push((
+                    distance(points[a], points[b]),
+                    a,
+                    b,
+                ));
+            }
+        }
+        expected.sort_unstable();
+        let expected: Vec<(usize, usize, i64)> = expected
+            .into_iter()
+            .map(|(distance, a, b)| (a, b, distance))
+            .collect();
+        let tree = KdTree::new(points);
+        assert_eq!(
+            expected,
+            tree.pairs().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_no_pairs() {
+        let tree = KdTree::new(scattered(1));
+        assert_eq!(None, tree.pairs().next());
+    }
+}
@@ -0,0 +1,150 @@
+use miette::SourceSpan;
+use nom::error::{
+    ContextError, ErrorKind, FromExternalError, ParseError,
+};
+use std::ops::Range;
+
+/// Where a parser failed and what it expected there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    /// the input left at the failure
+    pub input: &'a str,
+    pub kind: ErrorKind,
+    /// the innermost `context` the failure happened in
+    pub context: Option<&'static str>,
+}
+
+impl<'a> Error<'a> {
+    /// A failure at `input` in `context`
+    pub fn new(
+        input: &'a str,
+        context: &'static str,
+    ) -> Self {
+        Self {
+            input,
+            kind: ErrorKind::Verify,
+            context: Some(context),
+        }
+    }
+
+    /// What the parser expected, the context if there
+    /// is one
+    pub fn expected(&self) -> &str {
+        self.context
+            .unwrap_or_else(|| self.kind.description())
+    }
+
+    /// The byte offset of the failure in `source`, the
+    /// whole input the parser was given
+    pub fn offset(&self, source: &str) -> usize {
+        source.len() - self.input.len()
+    }
+
+    /// A diagnostic labelling the failure in `source`,
+    /// spanning the token it failed on
+    pub fn diagnostic(&self, source: &str) -> Diagnostic {
+        let offset = self.offset(source);
+        let token = self
+            .input
+            .find(char::is_whitespace)
+            .unwrap_or(self.input.len());
+        Diagnostic::new(
+            source,
+            offset..offset + token,
+            self.expected(),
+        )
+    }
+}
+
+/// A malformed puzzle input, labelled where it
+/// stopped parsing. Whoever read the input attaches
+/// it as the source code, see `aoc_core::input::Input`.
+#[derive(
+    Debug, Clone, thiserror::Error, miette::Diagnostic,
+)]
+#[error(
+    "expected {expected} at line {line}, column {column}"
+)]
+#[diagnostic(
+    code(aoc::parse),
+    help(
+        "look for a bad copy-paste or a truncated \
+         download in the input"
+    )
+)]
+pub struct Diagnostic {
+    pub expected: String,
+    /// 1-based, like editors show it
+    pub line: usize,
+    pub column: usize,
+    #[label("here")]
+    pub span: SourceSpan,
+}
+
+impl Diagnostic {
+    /// A failure at the bytes `span` of `source`, the
+    /// whole input
+    pub fn new(
+        source: &str,
+        span: Range<usize>,
+        expected: impl Into<String>,
+    ) -> Self {
+        let before = &source[..span.start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before
+            .rfind('\n')
+            .map_or(0, |newline| newline + 1);
+        let column =
+            before[line_start..].chars().count() + 1;
+        Self {
+            expected: expected.into(),
+            line,
+            column,
+            span: span.into(),
+        }
+    }
+}
+
+impl<'a> ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(
+        input: &'a str,
+        kind: ErrorKind,
+    ) -> Self {
+        Self {
+            input,
+            kind,
+            context: None,
+        }
+    }
+
+    /// keeps the innermost failure, it points closest
+    /// to the problem
+    fn append(
+        _input: &'a str,
+        _kind: ErrorKind,
+        other: Self,
+    ) -> Self {
+        other
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(
+        _input: &'a str,
+        context: &'static str,
+        mut other: Self,
+    ) -> Self {
+        other.context.get_or_insert(context);
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(
+        input: &'a str,
+        kind: ErrorKind,
+        _error: E,
+    ) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
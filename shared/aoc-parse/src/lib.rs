@@ -0,0 +1,413 @@
+//! nom combinators for the shapes puzzle inputs come
+//! in. Each one names what it expects with `context`,
+//! so [`finish`] can point at where and why parsing
+//! stopped.
+
+mod error;
+
+pub use error::{Diagnostic, Error};
+
+use aoc_grid::Grid;
+use miette::miette;
+use nom::{
+    Offset, Parser,
+    branch::alt,
+    character::complete::{
+        char, line_ending, multispace0, space0, space1,
+    },
+    combinator::{consumed, cut, eof, not, peek, value},
+    error::context,
+    multi::{many1, separated_list1},
+    sequence::{
+        delimited, preceded, separated_pair, terminated,
+    },
+};
+use std::ops::RangeInclusive;
+
+pub type IResult<'a, O> =
+    nom::IResult<&'a str, O, Error<'a>>;
+
+/// Runs `parser` over the whole input, trailing
+/// whitespace such as the final newline is skipped.
+/// Failures are a [`Diagnostic`] labelling where
+/// parsing stopped.
+pub fn finish<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<
+        &'a str,
+        Output = O,
+        Error = Error<'a>,
+    >,
+) -> miette::Result<O> {
+    let (rest, output) = match parser.parse(input) {
+        Ok(parsed) => parsed,
+        Err(
+            nom::Err::Error(error)
+            | nom::Err::Failure(error),
+        ) => {
+            return Err(error.diagnostic(input).into());
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            return Err(miette!("the input ended early"));
+        }
+    };
+    let (rest, _) = multispace0::<_, Error>(rest)
+        .expect("multispace0 always succeeds");
+    if !rest.is_empty() {
+        return Err(Error::new(
+            rest,
+            "the end of the input",
+        )
+        .diagnostic(input)
+        .into());
+    }
+    Ok(output)
+}
+
+/// `item` on every line. A line with something on it
+/// has to be an `item`, there's no backtracking out of
+/// a broken one, so errors point right at it. Blank
+/// lines end the list.
+pub fn lines<'a, O>(
+    item: impl Parser<&'a str, Output = O, Error = Error<'a>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<'a>>
+{
+    separated_list1(
+        terminated(
+            line_ending,
+            not(terminated(
+                space0,
+                alt((line_ending, eof)),
+            )),
+        ),
+        cut(item),
+    )
+}
+
+/// `item`s separated by commas, `1,2,3`. There's no
+/// backtracking out of a broken item, so errors point
+/// right at it.
+pub fn comma_list<'a, O>(
+    item: impl Parser<&'a str, Output = O, Error = Error<'a>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<'a>>
+{
+    separated_list1(char(','), cut(item))
+}
+
+/// An inclusive `a-b` range
+pub fn range<'a, T, P>(
+    bound: P,
+) -> impl Parser<
+    &'a str,
+    Output = RangeInclusive<T>,
+    Error = Error<'a>,
+>
+where
+    P: Parser<&'a str, Output = T, Error = Error<'a>>
+        + Clone,
+{
+    context(
+        "a range like `1-5`",
+        separated_pair(bound.clone(), char('-'), bound),
+    )
+    .map(|(start, end)| start..=end)
+}
+
+/// An `x,y` position
+pub fn coord2<'a, T, P>(
+    axis: P,
+) -> impl Parser<&'a str, Output = (T, T), Error = Error<'a>>
+where
+    P: Parser<&'a str, Output = T, Error = Error<'a>>
+        + Clone,
+{
+    context(
+        "a position like `1,2`",
+        separated_pair(axis.clone(), char(','), axis),
+    )
+}
+
+/// An `x,y,z` position
+pub fn coord3<'a, T, P>(
+    axis: P,
+) -> impl Parser<&'a str, Output = (T, T, T), Error = Error<'a>>
+where
+    P: Parser<&'a str, Output = T, Error = Error<'a>>
+        + Clone,
+{
+    context(
+        "a position like `1,2,3`",
+        (
+            axis.clone(),
+            preceded(char(','), axis.clone()),
+            preceded(char(','), axis),
+        ),
+    )
+}
+
+/// The empty line between sections
+pub fn blank_line(input: &str) -> IResult<'_, ()> {
+    value((), (line_ending, line_ending)).parse(input)
+}
+
+/// `section`s separated by empty lines
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, Output = O, Error = Error<'a>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<'a>>
+{
+    separated_list1(blank_line, section)
+}
+
+/// A line of `item`s separated and padded by spaces,
+/// ` 12  3 456 `
+pub fn row<'a, O>(
+    item: impl Parser<&'a str, Output = O, Error = Error<'a>>,
+) -> impl Parser<&'a str, Output = Vec<O>, Error = Error<'a>>
+{
+    context(
+        "a row of values separated by spaces",
+        delimited(
+            space0,
+            separated_list1(space1, item),
+            space0,
+        ),
+    )
+}
+
+/// Lines of whitespace aligned `item`s, read column
+/// by column. Every line needs as many values as the
+/// first.
+pub fn columns<'a, O>(
+    item: impl Parser<&'a str, Output = O, Error = Error<'a>>,
+) -> impl Parser<&'a str, Output = Vec<Vec<O>>, Error = Error<'a>>
+{
+    // backtracks out of a line that isn't a row, so
+    // something else, like a line of operators, can
+    // follow
+    let mut rows =
+        separated_list1(line_ending, consumed(row(item)));
+    move |input: &'a str| {
+        let (rest, rows) = rows.parse(input)?;
+        let width = equal_width(input, &rows)?;
+        let mut columns: Vec<Vec<O>> = (0..width)
+            .map(|_| Vec::with_capacity(rows.len()))
+            .collect();
+        for (_, values) in rows {
+            for (column, value) in
+                columns.iter_mut().zip(values)
+            {
+                column.push(value);
+            }
+        }
+        Ok((rest, columns))
+    }
+}
+
+/// A character map, `cell` parses a single character
+pub fn grid<'a, T>(
+    cell: impl Parser<&'a str, Output = T, Error = Error<'a>>,
+) -> impl Parser<&'a str, Output = Grid<T>, Error = Error<'a>>
+{
+    let mut rows = lines(consumed(terminated(
+        many1(context("a grid cell", cell)),
+        // points at the first character `cell` rejects
+        // rather than the row being short
+        cut(context(
+            "a grid cell",
+            peek(alt((line_ending, eof))),
+        )),
+    )));
+    move |input: &'a str| {
+        let (rest, rows) = rows.parse(input)?;
+        let width = equal_width(input, &rows)?;
+        let cells = rows
+            .into_iter()
+            .flat_map(|(_, row)| row)
+            .collect();
+        Ok((rest, Grid::from_vec(width, cells)))
+    }
+}
+
+/// The width of the first row, or a failure at the
+/// first row of a different width
+fn equal_width<'a, O>(
+    input: &'a str,
+    rows: &[(&'a str, Vec<O>)],
+) -> Result<usize, nom::Err<Error<'a>>> {
+    let width =
+        rows.first().map_or(0, |(_, row)| row.len());
+    match rows.iter().find(|(_, row)| row.len() != width) {
+        // errors hold the rest of the input from where
+        // they happened
+        Some((line, _)) => {
+            Err(nom::Err::Failure(Error::new(
+                &input[input.offset(line)..],
+                "a row as wide as the first",
+            )))
+        }
+        None => Ok(width),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::{self, one_of};
+    use rstest::rstest;
+
+    #[test]
+    fn test_comma_list_of_ranges() -> miette::Result<()> {
+        assert_eq!(
+            vec![11..=22, 95..=115],
+            finish(
+                "11-22,95-115\n",
+                comma_list(range(complete::u64))
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_coords() -> miette::Result<()> {
+        assert_eq!(
+            vec![(7, 1), (11, 1)],
+            finish(
+                "7,1\n11,1",
+                lines(coord2(complete::u32))
+            )?
+        );
+        assert_eq!(
+            vec![(162, 817, 812), (57, -618, 57)],
+            finish(
+                "162,817,812\n57,-618,57\n",
+                lines(coord3(complete::i32))
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sections() -> miette::Result<()> {
+        let input = "3-5\n10-14\n\n1\n5\n";
+        let (ranges, ids) = finish(
+            input,
+            separated_pair(
+                lines(range(complete::i64)),
+                blank_line,
+                lines(complete::i64),
+            ),
+        )?;
+        assert_eq!(vec![3..=5, 10..=14], ranges);
+        assert_eq!(vec![1, 5], ids);
+        assert_eq!(
+            vec![vec![1, 2], vec![3]],
+            finish(
+                "1\n2\n\n3",
+                sections(lines(complete::u8))
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_columns() -> miette::Result<()> {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+        let (columns, operators) = finish(
+            input,
+            separated_pair(
+                columns(complete::u64),
+                line_ending,
+                row(one_of("+*")),
+            ),
+        )?;
+        assert_eq!(
+            vec![
+                vec![123, 45, 6],
+                vec![328, 64, 98],
+                vec![51, 387, 215],
+                vec![64, 23, 314]
+            ],
+            columns
+        );
+        assert_eq!(vec!['*', '+', '*', '+'], operators);
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid() -> miette::Result<()> {
+        let grid =
+            finish("..@\n@@.\n", grid(one_of(".@")))?;
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!("..@\n@@.", grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_grid_bad_cell() {
+        let report =
+            finish("..@\n@#.\n", grid(one_of(".@")))
+                .unwrap_err();
+        assert_eq!(
+            "expected a grid cell at line 2, column 2",
+            report.to_string()
+        );
+    }
+
+    #[rstest]
+    #[case("11-22,95x115", "line 1, column 9")]
+    #[case("11-22,", "line 1, column 7")]
+    #[case("11-22,95-115\n,1-2", "line 2, column 1")]
+    fn test_errors_are_located(
+        #[case] input: &str,
+        #[case] location: &str,
+    ) {
+        let report =
+            finish(input, comma_list(range(complete::u64)))
+                .unwrap_err();
+        assert!(
+            report.to_string().contains(location),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn test_ragged_columns() {
+        let report =
+            finish("1 2 3\n4 5\n", columns(complete::u8))
+                .unwrap_err();
+        assert_eq!(
+            "expected a row as wide as the first at line 2, column 1",
+            report.to_string()
+        );
+    }
+
+    #[test]
+    fn test_error_context() {
+        let error = finish(
+            "7,1\n11;1",
+            lines(coord2(complete::u8)),
+        )
+        .unwrap_err();
+        assert_eq!(
+            "expected a position like `1,2` at line 2, column 3",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_error_span() {
+        let report = finish(
+            "11-22,95x115\n",
+            comma_list(range(complete::u64)),
+        )
+        .unwrap_err();
+        let diagnostic =
+            report.downcast_ref::<Diagnostic>().unwrap();
+        assert_eq!(
+            (1, 9),
+            (diagnostic.line, diagnostic.column)
+        );
+        assert_eq!(8, diagnostic.span.offset());
+        assert_eq!("x115".len(), diagnostic.span.len());
+    }
+}
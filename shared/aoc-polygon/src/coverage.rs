@@ -0,0 +1,319 @@
+use crate::Polygon;
+use glam::I64Vec2;
+use std::collections::VecDeque;
+
+/// A polygon with its coordinates compressed. Every
+/// vertex coordinate gets a row or column of its own and
+/// so does the gap to the next one, which is entirely
+/// inside or outside. Prefix sums over the tiles outside
+/// answer rectangle queries in constant time.
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    xs: Axis,
+    ys: Axis,
+    /// per compressed cell, row by row
+    inside: Vec<bool>,
+    /// `outside[y][x]` counts the tiles outside in the
+    /// cells above and left of `(x, y)`, one row and
+    /// column longer than `inside`
+    outside: Vec<i64>,
+    /// the compressed cell of each vertex
+    vertices: Vec<(usize, usize)>,
+}
+
+/// The distinct coordinates along one axis. Cell `0` and
+/// the last cell are a margin past both ends, odd cells
+/// are the coordinates and even ones the gaps between.
+#[derive(Debug, Clone)]
+struct Axis {
+    coordinates: Vec<i64>,
+}
+
+impl Axis {
+    fn new(coordinates: impl Iterator<Item = i64>) -> Self {
+        let mut coordinates: Vec<i64> =
+            coordinates.collect();
+        coordinates.sort_unstable();
+        coordinates.dedup();
+        Self { coordinates }
+    }
+
+    fn len(&self) -> usize {
+        2 * self.coordinates.len() + 1
+    }
+
+    /// The cell holding `coordinate`, `None` in the
+    /// margins
+    fn cell(&self, coordinate: i64) -> Option<usize> {
+        match self.coordinates.binary_search(&coordinate) {
+            Ok(i) => Some(2 * i + 1),
+            Err(0) => None,
+            Err(i) if i == self.coordinates.len() => None,
+            Err(i) => Some(2 * i),
+        }
+    }
+
+    /// How many tiles wide the cell is
+    fn width(&self, cell: usize) -> i64 {
+        if cell == 0 || cell == self.len() - 1 {
+            0
+        } else if cell % 2 == 1 {
+            1
+        } else {
+            let i = cell / 2;
+            self.coordinates[i]
+                - self.coordinates[i - 1]
+                - 1
+        }
+    }
+}
+
+impl Coverage {
+    pub fn new(polygon: &Polygon) -> Self {
+        let xs =
+            Axis::new(polygon.vertices.iter().map(|v| v.x));
+        let ys =
+            Axis::new(polygon.vertices.iter().map(|v| v.y));
+        let (width, height) = (xs.len(), ys.len());
+        let cell = |vertex: I64Vec2| {
+            (
+                xs.cell(vertex.x)
+                    .expect("vertices are on the axes"),
+                ys.cell(vertex.y)
+                    .expect("vertices are on the axes"),
+            )
+        };
+        let vertices: Vec<(usize, usize)> = polygon
+            .vertices
+            .iter()
+            .map(|&v| cell(v))
+            .collect();
+
+        let mut boundary = vec![false; width * height];
+        for (a, b) in polygon.edges() {
+            let ((ax, ay), (bx, by)) = (cell(a), cell(b));
+            for y in ay.min(by)..=ay.max(by) {
+                for x in ax.min(bx)..=ax.max(bx) {
+                    boundary[y * width + x] = true;
+                }
+            }
+        }
+
+        // flood the outside in from the margin
+        let mut outside = vec![false; width * height];
+        let mut queue = VecDeque::from([(0_usize, 0_usize)]);
+        outside[0] = true;
+        while let Some((x, y)) = queue.pop_front() {
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (x, y) in neighbors {
+                if x < width
+                    && y < height
+                    && !boundary[y * width + x]
+                    && !outside[y * width + x]
+                {
+                    outside[y * width + x] = true;
+                    queue.push_back((x, y));
+                }
+            }
+        }
+
+        let mut sums = vec![0; (width + 1) * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                let tiles = if outside[y * width + x] {
+                    xs.width(x) * ys.width(y)
+                } else {
+                    0
+                };
+                sums[(y + 1) * (width + 1) + x + 1] = tiles
+                    + sums[y * (width + 1) + x + 1]
+                    + sums[(y + 1) * (width + 1) + x]
+                    - sums[y * (width + 1) + x];
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            inside: outside
+                .iter()
+                .map(|&out| !out)
+                .collect(),
+            outside: sums,
+            vertices,
+        }
+    }
+
+    /// Whether `point` is on or inside the polygon
+    pub fn contains(&self, point: I64Vec2) -> bool {
+        match (
+            self.xs.cell(point.x),
+            self.ys.cell(point.y),
+        ) {
+            (Some(x), Some(y)) => {
+                self.inside[y * self.xs.len() + x]
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether every tile of the rectangle with opposite
+    /// corners `a` and `b` is on or inside the polygon
+    pub fn contains_rect(
+        &self,
+        a: I64Vec2,
+        b: I64Vec2,
+    ) -> bool {
+        let (min, max) = (a.min(b), a.max(b));
+        match (
+            self.xs.cell(min.x),
+            self.ys.cell(min.y),
+            self.xs.cell(max.x),
+            self.ys.cell(max.y),
+        ) {
+            (Some(x0), Some(y0), Some(x1), Some(y1)) => {
+                self.outside_between((x0, y0), (x1, y1))
+                    == 0
+            }
+            _ => false,
+        }
+    }
+
+    /// [`Coverage::contains_rect`] for the rectangle
+    /// between vertices `a` and `b`, by their index,
+    /// without looking the coordinates up
+    pub fn contains_vertex_rect(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> bool {
+        let ((ax, ay), (bx, by)) =
+            (self.vertices[a], self.vertices[b]);
+        self.outside_between(
+            (ax.min(bx), ay.min(by)),
+            (ax.max(bx), ay.max(by)),
+        ) == 0
+    }
+
+    /// The tiles outside in the cells from `min` to
+    /// `max`, inclusive
+    fn outside_between(
+        &self,
+        (x0, y0): (usize, usize),
+        (x1, y1): (usize, usize),
+    ) -> i64 {
+        let stride = self.xs.len() + 1;
+        let sum = |x: usize, y: usize| {
+            self.outside[y * stride + x]
+        };
+        sum(x1 + 1, y1 + 1)
+            - sum(x0, y1 + 1)
+            - sum(x1 + 1, y0)
+            + sum(x0, y0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{example, polygon, u_shape};
+
+    fn rectangles(
+        polygon: &Polygon,
+    ) -> impl Iterator<Item = (I64Vec2, I64Vec2)> + '_ {
+        polygon.vertices().iter().flat_map(move |&a| {
+            polygon.vertices().iter().map(move |&b| (a, b))
+        })
+    }
+
+    /// Checks every tile of the rectangle one by one
+    fn brute_force(
+        polygon: &Polygon,
+        a: I64Vec2,
+        b: I64Vec2,
+    ) -> bool {
+        let (min, max) = (a.min(b), a.max(b));
+        (min.y..=max.y).all(|y| {
+            (min.x..=max.x).all(|x| {
+                polygon.contains(I64Vec2::new(x, y))
+            })
+        })
+    }
+
+    #[test]
+    fn test_contains_agrees() {
+        for polygon in [example(), u_shape()] {
+            let coverage = polygon.coverage();
+            for y in -2..=13 {
+                for x in -2..=13 {
+                    let point = I64Vec2::new(x, y);
+                    assert_eq!(
+                        polygon.contains(point),
+                        coverage.contains(point),
+                        "{point}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rectangles_agree() {
+        for polygon in [example(), u_shape()] {
+            let coverage = polygon.coverage();
+            for (a, b) in rectangles(&polygon) {
+                assert_eq!(
+                    brute_force(&polygon, a, b),
+                    coverage.contains_rect(a, b),
+                    "{a} {b}"
+                );
+            }
+            for a in 0..polygon.vertices().len() {
+                for b in 0..polygon.vertices().len() {
+                    assert_eq!(
+                        coverage.contains_rect(
+                            polygon.vertices()[a],
+                            polygon.vertices()[b]
+                        ),
+                        coverage.contains_vertex_rect(a, b)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rectangle_across_a_gap() {
+        // both arms' inner edges are inside, the tiles
+        // between them aren't
+        let coverage = u_shape().coverage();
+        assert!(!coverage.contains_rect(
+            I64Vec2::new(3, 10),
+            I64Vec2::new(7, 3)
+        ));
+        assert!(coverage.contains_rect(
+            I64Vec2::new(0, 0),
+            I64Vec2::new(10, 3)
+        ));
+        // a one tile wide notch has no tiles outside
+        let notched = polygon(&[
+            (0, 0),
+            (4, 0),
+            (4, 4),
+            (3, 4),
+            (3, 1),
+            (2, 1),
+            (2, 4),
+            (0, 4),
+        ]);
+        assert!(notched.coverage().contains_rect(
+            I64Vec2::ZERO,
+            I64Vec2::new(4, 4)
+        ));
+    }
+}
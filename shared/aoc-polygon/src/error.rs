@@ -0,0 +1,23 @@
+use glam::I64Vec2;
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {
+    #[error("a polygon needs at least one vertex")]
+    #[diagnostic(code(aoc::polygon::empty))]
+    Empty,
+
+    #[error(
+        "vertices {from} and {to} don't share a row or \
+         column"
+    )]
+    #[diagnostic(
+        code(aoc::polygon::diagonal_edge),
+        help(
+            "edges are axis-aligned, so each vertex lines \
+             up with the next and the last with the first"
+        )
+    )]
+    DiagonalEdge { from: I64Vec2, to: I64Vec2 },
+}
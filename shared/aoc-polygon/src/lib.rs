@@ -0,0 +1,218 @@
+//! Polygons with axis-aligned edges, given as their
+//! vertices in order around the outline. Coordinates
+//! are tiles: a polygon covers the tiles on its outline
+//! and inside it, see [`Coverage`] for fast queries.
+
+mod coverage;
+pub mod error;
+
+pub use coverage::Coverage;
+pub use error::Error;
+
+use glam::I64Vec2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<I64Vec2>,
+}
+
+impl Polygon {
+    /// Fails unless every vertex shares a row or column
+    /// with the next, wrapping around to the first
+    pub fn new(
+        vertices: Vec<I64Vec2>,
+    ) -> Result<Self, Error> {
+        if vertices.is_empty() {
+            return Err(Error::Empty);
+        }
+        let polygon = Self { vertices };
+        if let Some((from, to)) =
+            polygon.edges().find(|(from, to)| {
+                from.x != to.x && from.y != to.y
+            })
+        {
+            return Err(Error::DiagonalEdge { from, to });
+        }
+        Ok(polygon)
+    }
+
+    pub fn vertices(&self) -> &[I64Vec2] {
+        &self.vertices
+    }
+
+    /// Each vertex with the next, the last closing the
+    /// loop back to the first
+    pub fn edges(
+        &self,
+    ) -> impl Iterator<Item = (I64Vec2, I64Vec2)> + '_ {
+        self.vertices.iter().copied().zip(
+            self.vertices.iter().copied().cycle().skip(1),
+        )
+    }
+
+    /// The area enclosed by the outline through the
+    /// vertices, by the shoelace formula
+    pub fn area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.perp_dot(b))
+            .sum::<i64>()
+            .abs()
+            / 2
+    }
+
+    /// The length of the outline
+    pub fn perimeter(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| (b - a).abs().element_sum())
+            .sum()
+    }
+
+    /// The number of tiles on and inside the outline.
+    /// By Pick's theorem the inside holds
+    /// `area - perimeter / 2 + 1` of them.
+    pub fn tiles(&self) -> i64 {
+        self.area() + self.perimeter() / 2 + 1
+    }
+
+    /// Whether `point` is on the outline
+    pub fn on_boundary(&self, point: I64Vec2) -> bool {
+        self.edges().any(|(a, b)| {
+            point.cmpge(a.min(b)).all()
+                && point.cmple(a.max(b)).all()
+        })
+    }
+
+    /// Whether `point` is on or inside the outline,
+    /// walking every edge. Build a [`Coverage`] to ask
+    /// many times.
+    pub fn contains(&self, point: I64Vec2) -> bool {
+        if self.on_boundary(point) {
+            return true;
+        }
+        // a ray to the right crosses the outline an odd
+        // number of times from inside, each vertical edge
+        // counts its lower end but not its upper one
+        self.edges()
+            .filter(|(a, b)| {
+                a.x == b.x
+                    && a.x > point.x
+                    && (a.y.min(b.y)..a.y.max(b.y))
+                        .contains(&point.y)
+            })
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Compresses the coordinates and classifies every
+    /// region, for fast point and rectangle queries
+    pub fn coverage(&self) -> Coverage {
+        Coverage::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    /// The day-09 example, a blob with a notch
+    pub(crate) fn example() -> Polygon {
+        polygon(&[
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ])
+    }
+
+    /// A U, the gap between its arms is outside
+    pub(crate) fn u_shape() -> Polygon {
+        polygon(&[
+            (0, 0),
+            (10, 0),
+            (10, 10),
+            (7, 10),
+            (7, 3),
+            (3, 3),
+            (3, 10),
+            (0, 10),
+        ])
+    }
+
+    pub(crate) fn polygon(
+        vertices: &[(i64, i64)],
+    ) -> Polygon {
+        Polygon::new(
+            vertices
+                .iter()
+                .map(|&(x, y)| I64Vec2::new(x, y))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_diagonal_edge() {
+        let error = Polygon::new(vec![
+            I64Vec2::new(0, 0),
+            I64Vec2::new(4, 0),
+            I64Vec2::new(3, 2),
+        ])
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            Error::DiagonalEdge { from, to }
+                if from == I64Vec2::new(4, 0)
+                    && to == I64Vec2::new(3, 2)
+        ));
+        assert!(matches!(
+            Polygon::new(vec![]),
+            Err(Error::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_area() {
+        let square =
+            polygon(&[(0, 0), (3, 0), (3, 3), (0, 3)]);
+        assert_eq!(9, square.area());
+        assert_eq!(12, square.perimeter());
+        assert_eq!(16, square.tiles());
+
+        assert_eq!(100 - 28, u_shape().area());
+        assert_eq!(
+            (0..=11)
+                .flat_map(|x| (0..=11).map(move |y| (x, y)))
+                .filter(|&(x, y)| {
+                    example().contains(I64Vec2::new(x, y))
+                })
+                .count() as i64,
+            example().tiles()
+        );
+    }
+
+    #[rstest]
+    #[case((0, 0), true)]
+    #[case((5, 0), true)]
+    #[case((5, 2), true)]
+    #[case((5, 3), true)]
+    #[case((5, 4), false)]
+    #[case((3, 7), true)]
+    #[case((8, 10), true)]
+    #[case((11, 5), false)]
+    #[case((-1, 5), false)]
+    fn test_contains(
+        #[case] (x, y): (i64, i64),
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            expected,
+            u_shape().contains(I64Vec2::new(x, y))
+        );
+    }
+}
@@ -0,0 +1,678 @@
+use aoc_bench::{
+    Comparison, History, Run, divan, history::HISTORY_FILE,
+};
+use aoc_client::{Client, Ledger, Puzzle, Verdict};
+use aoc_core::{
+    Day, Part, Registry,
+    answers::{ANSWERS_FILE, KnownAnswers},
+    input::{self, Input},
+    variants,
+};
+use clap::{Parser, Subcommand};
+use miette::{Context, IntoDiagnostic, miette};
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A year's workspace of days, each year's `aoc`
+/// binary runs the shared commands against its own
+#[derive(Debug, Clone, Copy)]
+pub struct Workspace {
+    pub year: u16,
+    pub registry: Registry,
+    /// the directory holding every day's crate
+    pub dir: &'static Path,
+}
+
+#[derive(Parser, Debug)]
+#[clap(version)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run a single solution and print the answer
+    Run {
+        /// formatted as `day-01` to match the crate
+        /// names
+        day: String,
+        /// `part1` or `part2`
+        part: Part,
+        /// an alternative implementation, such as
+        /// `convolve` for `part2_convolve`
+        #[clap(long)]
+        variant: Option<String>,
+        /// `-` reads from stdin, defaults to
+        /// `<day>/input<N>.txt` in the workspace or
+        /// `$AOC_INPUTS`
+        #[clap(long)]
+        input: Option<PathBuf>,
+    },
+    /// List every registered day, part and variant
+    List,
+    /// Run every variant of a part on the same input
+    /// and fail if their answers differ
+    Check {
+        /// a single day, defaults to every day
+        day: Option<String>,
+        /// a single part, defaults to both parts
+        part: Option<Part>,
+        /// defaults to each day's own input, days
+        /// without one are skipped
+        #[clap(long, requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Submit the answer a solution gives, unless the
+    /// ledger of earlier submissions already has a
+    /// verdict for it
+    Submit {
+        /// formatted as `day-01` to match the crate
+        /// names
+        day: String,
+        /// `part1` or `part2`
+        part: Part,
+        /// submit a variant's answer instead of the
+        /// main solution's
+        #[clap(long)]
+        variant: Option<String>,
+        /// `-` reads from stdin, defaults to
+        /// `<day>/input<N>.txt` in the workspace or
+        /// `$AOC_INPUTS`
+        #[clap(long)]
+        input: Option<PathBuf>,
+        /// defaults to the workspace's year
+        #[clap(long)]
+        year: Option<u16>,
+        /// defaults to `$AOC_BASE_URL`, or
+        /// adventofcode.com
+        #[clap(long)]
+        base_url: Option<String>,
+    },
+    /// Scrape the examples from a day's puzzle
+    /// description into `<day>/examples/`, where the
+    /// part tests load them from
+    Examples {
+        /// formatted as `day-01` to match the crate
+        /// names
+        day: String,
+        /// a saved copy of the puzzle page, fetched when
+        /// not given
+        #[clap(long)]
+        html: Option<PathBuf>,
+        /// defaults to the workspace's year
+        #[clap(long)]
+        year: Option<u16>,
+        /// defaults to `$AOC_BASE_URL`, or
+        /// adventofcode.com
+        #[clap(long)]
+        base_url: Option<String>,
+    },
+    /// Keep benchmark results as csv and compare runs
+    Bench {
+        #[command(subcommand)]
+        command: BenchCommand,
+    },
+    /// Download a day's input, or read it from the
+    /// cache, into `<day>/input1.txt` and
+    /// `<day>/input2.txt`
+    Fetch {
+        /// formatted as `day-01` to match the crate
+        /// names
+        day: String,
+        /// defaults to the workspace's year
+        #[clap(long)]
+        year: Option<u16>,
+        /// defaults to `$AOC_BASE_URL`, or
+        /// adventofcode.com
+        #[clap(long)]
+        base_url: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum BenchCommand {
+    /// Parse divan's output and append it to
+    /// `benchmarks.csv` under the current commit
+    Record {
+        /// files holding divan's output, read from
+        /// stdin when none are given
+        files: Vec<PathBuf>,
+    },
+    /// Compare the medians of two runs, failing when
+    /// any benchmark slowed down beyond the threshold
+    Compare {
+        /// the commit to compare against, defaults to
+        /// the latest run before `head` that measured
+        /// any of the same benchmarks
+        #[clap(long)]
+        base: Option<String>,
+        /// the commit to check, defaults to the latest
+        /// run
+        #[clap(long)]
+        head: Option<String>,
+        /// the slowdown in percent that counts as a
+        /// regression
+        #[clap(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+/// Parses the command line and runs the command
+/// against `workspace`
+pub fn run(workspace: Workspace) -> miette::Result<()> {
+    let args = Args::parse();
+    match args.command {
+        Command::Run {
+            day,
+            part,
+            variant,
+            input,
+        } => {
+            let result = workspace.solve(
+                &day,
+                part,
+                variant.as_deref(),
+                input,
+            )?;
+            println!("{}", result);
+        }
+        Command::List => {
+            for Day { name, solutions } in
+                workspace.registry.days()
+            {
+                for solution in solutions.iter() {
+                    match solution.variant {
+                        Some(variant) => println!(
+                            "{name} {} {variant}",
+                            solution.part
+                        ),
+                        None => println!(
+                            "{name} {}",
+                            solution.part
+                        ),
+                    }
+                }
+            }
+        }
+        Command::Check { day, part, input } => {
+            workspace.check(day.as_deref(), part, input)?;
+        }
+        Command::Submit {
+            day,
+            part,
+            variant,
+            input,
+            year,
+            base_url,
+        } => {
+            let answer = workspace.solve(
+                &day,
+                part,
+                variant.as_deref(),
+                input,
+            )?;
+            workspace.submit(
+                &day,
+                part,
+                &answer,
+                year.unwrap_or(workspace.year),
+                base_url,
+            )?;
+        }
+        Command::Examples {
+            day,
+            html,
+            year,
+            base_url,
+        } => {
+            workspace.examples(
+                &day,
+                html,
+                year.unwrap_or(workspace.year),
+                base_url,
+            )?;
+        }
+        Command::Bench { command } => {
+            workspace.bench(command)?
+        }
+        Command::Fetch {
+            day,
+            year,
+            base_url,
+        } => {
+            workspace.fetch(
+                &day,
+                year.unwrap_or(workspace.year),
+                base_url,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+impl Workspace {
+    /// Runs every registered solution, variants
+    /// included, on the local inputs and compares it
+    /// with the accepted answer in `answers.toml`.
+    /// Parts without an input or an accepted answer
+    /// are skipped with a notice.
+    pub fn check_answers(&self) -> miette::Result<()> {
+        let known = KnownAnswers::load(
+            self.dir.join(ANSWERS_FILE),
+        )?;
+
+        let mut checked = 0;
+        let mut wrong = vec![];
+        for day in self.registry.days() {
+            for part in [Part::One, Part::Two] {
+                let Some(expected) =
+                    known.get(day.name, part)
+                else {
+                    eprintln!(
+                        "skipping {} {part}, no accepted answer in {ANSWERS_FILE}",
+                        day.name
+                    );
+                    continue;
+                };
+                let path = input::default_path(
+                    &self.dir.join(day.name),
+                    part,
+                );
+                let Ok(file) = input::read(&path) else {
+                    eprintln!(
+                        "skipping {} {part}, no input at {}",
+                        day.name,
+                        path.display()
+                    );
+                    continue;
+                };
+
+                for (name, answer) in
+                    variants::run_variants(day, part, &file)
+                        .answers
+                {
+                    checked += 1;
+                    match answer {
+                        Ok(answer) if answer == expected => {}
+                        Ok(answer) => wrong.push(format!(
+                            "{} {name}: {answer}, expected {expected}",
+                            day.name
+                        )),
+                        Err(report) => wrong.push(format!(
+                            "{} {name}: failed, {report}",
+                            day.name
+                        )),
+                    }
+                }
+            }
+        }
+
+        eprintln!("checked {checked} solutions");
+        if !wrong.is_empty() {
+            return Err(miette!(
+                code = "aoc::answers::wrong",
+                "solutions no longer give the accepted \
+                 answer\n{}",
+                wrong.join("\n")
+            ));
+        }
+        Ok(())
+    }
+
+    /// Runs a solution on its input, the default input
+    /// unless `input` is given
+    fn solve(
+        &self,
+        day: &str,
+        part: Part,
+        variant: Option<&str>,
+        input: Option<PathBuf>,
+    ) -> miette::Result<String> {
+        let solution =
+            self.registry.solution(day, part, variant)?;
+        let path = input.unwrap_or_else(|| {
+            input::default_path(&self.dir.join(day), part)
+        });
+        let file = Input::load(&path)?;
+        file.with_source((solution.process)(&file))
+            .wrap_err_with(|| {
+                format!("process {day} {}", solution.name())
+            })
+    }
+
+    fn check(
+        &self,
+        day: Option<&str>,
+        part: Option<Part>,
+        input: Option<PathBuf>,
+    ) -> miette::Result<()> {
+        let days = match day {
+            Some(day) => vec![*self.registry.day(day)?],
+            None => self.registry.days().to_vec(),
+        };
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec![Part::One, Part::Two],
+        };
+
+        let mut disagreements = 0;
+        for day in days.iter() {
+            for &part in parts.iter() {
+                // nothing to compare against
+                if day.solutions_for(part).count() < 2 {
+                    continue;
+                }
+                let file = match &input {
+                    Some(path) => input::read(path)?,
+                    None => {
+                        let path = input::default_path(
+                            &self.dir.join(day.name),
+                            part,
+                        );
+                        let Ok(file) = input::read(&path)
+                        else {
+                            eprintln!(
+                                "skipping {} {part}, no input at {}",
+                                day.name,
+                                path.display()
+                            );
+                            continue;
+                        };
+                        file
+                    }
+                };
+
+                let answers = variants::run_variants(
+                    day, part, &file,
+                );
+                match answers.agreed() {
+                    Some(answer) => println!(
+                        "{} {part}: {} variants agree on {answer}",
+                        day.name,
+                        answers.answers.len()
+                    ),
+                    None => {
+                        disagreements += 1;
+                        eprint!("{answers}");
+                    }
+                }
+            }
+        }
+
+        if disagreements > 0 {
+            return Err(miette!(
+                code = "aoc::variants::disagree",
+                "variants disagree for {disagreements} part(s)"
+            ));
+        }
+        Ok(())
+    }
+
+    fn submit(
+        &self,
+        day: &str,
+        part: Part,
+        answer: &str,
+        year: u16,
+        base_url: Option<String>,
+    ) -> miette::Result<()> {
+        let puzzle = Puzzle::from_day_name(year, day)?;
+        let client = client(base_url);
+        let path = Ledger::default_path(client.cache_dir())
+            .ok_or_else(|| {
+                miette!(
+                    help =
+                        "set `AOC_LEDGER` or `AOC_CACHE`",
+                    "nowhere to keep the submission ledger"
+                )
+            })?;
+        let mut ledger = Ledger::open(path)?;
+
+        let verdict = client.submit(
+            puzzle,
+            part,
+            answer,
+            &mut ledger,
+        )?;
+        println!("{puzzle} {part}: {answer} is {verdict}");
+        match verdict {
+            Verdict::Correct
+                if puzzle.year == self.year =>
+            {
+                let mut answers = KnownAnswers::load(
+                    self.dir.join(ANSWERS_FILE),
+                )?;
+                answers.insert(day, part, answer.trim());
+                answers.save()?;
+                println!(
+                    "recorded in {}",
+                    answers.path().display()
+                );
+                Ok(())
+            }
+            Verdict::Correct | Verdict::AlreadySolved => {
+                Ok(())
+            }
+            verdict => Err(miette!(
+                code = "aoc::submit",
+                "{puzzle} {part} was not accepted, {verdict}"
+            )),
+        }
+    }
+
+    fn examples(
+        &self,
+        day: &str,
+        html: Option<PathBuf>,
+        year: u16,
+        base_url: Option<String>,
+    ) -> miette::Result<()> {
+        let puzzle = Puzzle::from_day_name(year, day)?;
+        let page = match html {
+            Some(path) => std::fs::read_to_string(&path)
+                .into_diagnostic()
+                .wrap_err_with(|| {
+                    format!("read {}", path.display())
+                })?,
+            None => client(base_url).puzzle_page(puzzle)?,
+        };
+
+        let examples = aoc_client::examples::scrape(&page);
+        if examples.is_empty() {
+            return Err(miette!(
+                help = "add `examples/part1.txt` and \
+                        `examples/part1.answer` by hand",
+                "found no examples for {puzzle}"
+            ));
+        }
+        let day_dir = self.dir.join(day);
+        for (part, example) in examples {
+            example.write(&day_dir)?;
+            println!(
+                "{puzzle} {part}: {} lines, answer {}",
+                example.input.lines().count(),
+                example.answer
+            );
+        }
+        Ok(())
+    }
+
+    fn bench(
+        &self,
+        command: BenchCommand,
+    ) -> miette::Result<()> {
+        let mut history =
+            History::open(self.dir.join(HISTORY_FILE))?;
+        match command {
+            BenchCommand::Record { files } => {
+                let output = if files.is_empty() {
+                    input::read(Path::new(input::STDIN))?
+                } else {
+                    files
+                        .iter()
+                        .map(|path| input::read(path))
+                        .collect::<miette::Result<String>>(
+                        )?
+                };
+                let measurements = divan::parse(&output);
+                if measurements.is_empty() {
+                    return Err(miette!(
+                        help = "pass the output of `cargo bench`",
+                        "found no divan benchmarks to record"
+                    ));
+                }
+
+                let run = Run {
+                    timestamp: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .into_diagnostic()?
+                        .as_secs(),
+                    commit: git_commit(self.dir),
+                };
+                println!(
+                    "recorded {} benchmarks for {run} in {}",
+                    measurements.len(),
+                    history.path().display()
+                );
+                history.append(run, measurements)?;
+            }
+            BenchCommand::Compare {
+                base,
+                head,
+                threshold,
+            } => {
+                let find = |commit: &str| {
+                    history.find_run(commit).ok_or_else(|| {
+                        miette!(
+                            "no benchmarks recorded for `{commit}`"
+                        )
+                    })
+                };
+                let runs = history.runs();
+                let head = match head {
+                    Some(commit) => find(&commit)?,
+                    None => runs
+                        .last()
+                        .cloned()
+                        .ok_or_else(|| {
+                            miette!(
+                                "no benchmarks recorded yet"
+                            )
+                        })?,
+                };
+                let base = match base {
+                    Some(commit) => find(&commit)?,
+                    None => history
+                        .previous_run(&head)
+                        .ok_or_else(|| {
+                            miette!(
+                                help = "record another run with \
+                                        `just bench-all`",
+                                "no run before {head} measured \
+                                 any of its benchmarks"
+                            )
+                        })?,
+                };
+
+                let comparison = Comparison::new(
+                    &history.measurements(&base),
+                    &history.measurements(&head),
+                    threshold,
+                );
+                // nothing to compare would pass any check
+                if comparison.changes.is_empty() {
+                    return Err(miette!(
+                        code = "aoc::bench::compare",
+                        "{base} and {head} have no benchmarks \
+                         in common"
+                    ));
+                }
+                println!("{base} -> {head}");
+                print!("{comparison}");
+                let regressions =
+                    comparison.regressions().count();
+                if regressions > 0 {
+                    return Err(miette!(
+                        code = "aoc::bench::regression",
+                        "{regressions} benchmark(s) are more than \
+                         {threshold}% slower"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn fetch(
+        &self,
+        day: &str,
+        year: u16,
+        base_url: Option<String>,
+    ) -> miette::Result<()> {
+        let puzzle = Puzzle::from_day_name(year, day)?;
+        let client = client(base_url);
+        let file = client.input(puzzle)?;
+
+        // both parts share the same input, the second
+        // file only exists so each part can be pointed
+        // at a different one
+        for part in [Part::One, Part::Two] {
+            let path = input::default_path(
+                &self.dir.join(day),
+                part,
+            );
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)
+                    .into_diagnostic()?;
+            }
+            std::fs::write(&path, &file)
+                .into_diagnostic()
+                .wrap_err_with(|| {
+                    format!("write {}", path.display())
+                })?;
+            println!("{puzzle} {part}: {}", path.display());
+        }
+        Ok(())
+    }
+}
+
+fn client(base_url: Option<String>) -> Client {
+    match base_url {
+        Some(base_url) => {
+            Client::from_env().with_base_url(base_url)
+        }
+        None => Client::from_env(),
+    }
+}
+
+/// The short hash of `HEAD`, `-dirty` when there are
+/// uncommitted changes
+fn git_commit(dir: &Path) -> String {
+    let git = |args: &[&str]| {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .trim()
+                    .to_string()
+            })
+    };
+    let Some(commit) =
+        git(&["rev-parse", "--short", "HEAD"])
+    else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain"]) {
+        Some(status) if !status.is_empty() => {
+            format!("{commit}-dirty")
+        }
+        _ => commit,
+    }
+}
@@ -0,0 +1,62 @@
+use {{crate_name}}::*;
+use aoc_core::{Part, Solver, input};
+use std::sync::LazyLock;
+
+static INPUT1: LazyLock<String> = LazyLock::new(|| {
+    input::for_bench(env!("CARGO_MANIFEST_DIR"), Part::One)
+});
+
+static INPUT2: LazyLock<String> = LazyLock::new(|| {
+    input::for_bench(env!("CARGO_MANIFEST_DIR"), Part::Two)
+});
+
+static PARSED1: LazyLock<<{{project-name | upper_camel_case}} as Solver>::Parsed> =
+    LazyLock::new(|| {{project-name | upper_camel_case}}::parse(&INPUT1).unwrap());
+
+static PARSED2: LazyLock<<{{project-name | upper_camel_case}} as Solver>::Parsed> =
+    LazyLock::new(|| {{project-name | upper_camel_case}}::parse(&INPUT2).unwrap());
+
+fn main() {
+    // Read inputs before anything is timed
+    input::bench_divan(
+        env!("CARGO_MANIFEST_DIR"),
+        |part| {
+            match part {
+                Part::One => LazyLock::force(&PARSED1),
+                Part::Two => LazyLock::force(&PARSED2),
+            };
+        },
+    )
+    // Run registered benchmarks.
+    .main();
+}
+
+#[divan::bench]
+fn parse() {
+    {{project-name | upper_camel_case}}::parse(divan::black_box(&INPUT1)).unwrap();
+}
+
+#[divan::bench]
+fn part1() {
+    part1::process(divan::black_box(&INPUT1)).unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part2::process(divan::black_box(&INPUT2)).unwrap();
+}
+
+/// `part1` and `part2` on already parsed input
+mod solve {
+    use super::*;
+
+    #[divan::bench]
+    fn part1() {
+        {{project-name | upper_camel_case}}::part1(divan::black_box(&PARSED1)).unwrap();
+    }
+
+    #[divan::bench]
+    fn part2() {
+        {{project-name | upper_camel_case}}::part2(divan::black_box(&PARSED2)).unwrap();
+    }
+}
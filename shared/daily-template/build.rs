@@ -0,0 +1,5 @@
+fn main() {
+    // the part tests have a case per example file, pick
+    // up newly scraped ones
+    println!("cargo::rerun-if-changed=examples");
+}
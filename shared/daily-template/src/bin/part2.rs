@@ -0,0 +1,21 @@
+use aoc_core::{Part, Solver, input};
+use {{crate_name}}::{{project-name | upper_camel_case}};
+use miette::Context;
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let file = input::from_args(
+        env!("CARGO_MANIFEST_DIR"),
+        Part::Two,
+    )?;
+    let result = file
+        .with_source({{project-name | upper_camel_case}}::run(
+            &file,
+            Part::Two,
+        ))
+        .context("process part 2")?;
+    println!("{}", result);
+    Ok(())
+}
@@ -0,0 +1,7 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// Ways the input can't be solved, give each variant
+/// a `code(day_xx::...)` and some help
+#[derive(Debug, Error, Diagnostic)]
+pub enum Error {}
@@ -0,0 +1,32 @@
+use aoc_core::{Day, Part, Solution, Solver};
+
+pub mod error;
+pub mod parse;
+pub mod part1;
+pub mod part2;
+
+pub struct {{project-name | upper_camel_case}};
+
+impl Solver for {{project-name | upper_camel_case}} {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed> {
+        parse::parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> miette::Result<String> {
+        part1::solve(parsed)
+    }
+
+    fn part2(parsed: &Self::Parsed) -> miette::Result<String> {
+        part2::solve(parsed)
+    }
+}
+
+pub const DAY: Day = Day {
+    name: env!("CARGO_PKG_NAME"),
+    solutions: &[
+        Solution::new(Part::One, part1::process),
+        Solution::new(Part::Two, part2::process),
+    ],
+};
@@ -0,0 +1,3 @@
+pub fn parse(input: &str) -> miette::Result<Vec<String>> {
+    Ok(input.lines().map(String::from).collect())
+}
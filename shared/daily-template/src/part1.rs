@@ -0,0 +1,37 @@
+use crate::parse::parse;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<String> {
+    solve(&parse(input)?)
+}
+
+pub fn solve(_lines: &[String]) -> miette::Result<String> {
+    todo!("{{project-name}} - part 1");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{Part, examples};
+    use rstest::rstest;
+    use std::path::PathBuf;
+
+    #[rstest]
+    fn test_process(
+        #[files("examples/part1*.txt")]
+        // a case of its own until part 1 has examples
+        #[files("examples")]
+        #[dirs]
+        path: PathBuf,
+    ) -> miette::Result<()> {
+        if let Some(example) =
+            examples::case(&path, Part::One)?
+        {
+            assert_eq!(
+                example.answer,
+                process(&example.input)?
+            );
+        }
+        Ok(())
+    }
+}